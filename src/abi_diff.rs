@@ -0,0 +1,349 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, AstVisitorRecursive, ModuleContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, FnArgs, ItemKind, ItemTraitItem};
+use sway_types::Spanned;
+
+/// The ABI declarations and logged types of a project.
+#[derive(Clone, Debug, Default)]
+pub struct AbiLayout {
+    pub fns: Vec<AbiFnLayout>,
+    pub logged_types: Vec<LoggedTypeLayout>,
+}
+
+/// A function declared in an `abi` block.
+#[derive(Clone, Debug)]
+pub struct AbiFnLayout {
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub abi_name: String,
+    pub name: String,
+    pub arg_tys: Vec<String>,
+    pub return_ty: String,
+    pub storage_access: Vec<String>,
+    pub is_payable: bool,
+}
+
+impl AbiFnLayout {
+    /// Gets the signature of the function without argument names, i.e: `fn transfer(Identity, u64) -> bool`
+    pub fn signature(&self) -> String {
+        format!(
+            "fn {}({}){}",
+            self.name,
+            self.arg_tys.join(", "),
+            if self.return_ty == "()" { String::new() } else { format!(" -> {}", self.return_ty) },
+        )
+    }
+
+    /// Describes the `#[storage]` attribute of the function, i.e: `#[storage(read, write)]`
+    pub fn storage_attribute(&self) -> String {
+        if self.storage_access.is_empty() {
+            "no `#[storage]` attribute".to_string()
+        } else {
+            format!("`#[storage({})]`", self.storage_access.join(", "))
+        }
+    }
+}
+
+/// A type which is logged by a project, i.e: `log(Transfer { ... })`
+#[derive(Clone, Debug)]
+pub struct LoggedTypeLayout {
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub name: String,
+}
+
+/// Attempts to get the name of the type of a logged value, i.e: `Transfer` for `Transfer { ... }` or `Event` for `Event::Transfer(...)`
+pub fn get_logged_ty_name(expr: &Expr) -> Option<String> {
+    let path = match expr {
+        Expr::Struct { path, .. } => return Some(utils::path_expr_to_string(path)),
+        Expr::Path(path) => path,
+        Expr::FuncApp { func, .. } => match func.as_ref() {
+            Expr::Path(path) => path,
+            _ => return None,
+        },
+        Expr::Parens(parens) => return get_logged_ty_name(parens.inner.as_ref()),
+        _ => return None,
+    };
+
+    let segment_names = std::iter::once(path.prefix.name.as_str())
+        .chain(path.suffix.iter().map(|(_, segment)| segment.name.as_str()))
+        .collect::<Vec<_>>();
+
+    let is_ty_name = |name: &str| name.starts_with(|c: char| c.is_ascii_uppercase());
+
+    // Strip the enum variant or constructor function when it follows a type name, i.e: `Event::Transfer` or `Transfer::new`
+    if segment_names.len() > 1 && is_ty_name(segment_names[segment_names.len() - 2]) {
+        let mut path = path.clone();
+        path.suffix.pop();
+
+        return Some(utils::path_expr_to_string(&path));
+    }
+
+    // Otherwise the path itself must name a type, i.e: `Paused` or `events::Paused`, rather than a function returning the logged value
+    if !is_ty_name(segment_names.last()?) {
+        return None;
+    }
+
+    Some(utils::path_expr_to_string(path))
+}
+
+/// Attempts to get the functions of the `abi` blocks declared in all of the parsed files of `project`, along with the types they log.
+pub fn abi_layout(project: &mut Project) -> Result<AbiLayout, Error> {
+    let modules = project.modules();
+
+    let mut module_paths = modules.borrow().keys().cloned().collect::<Vec<_>>();
+    module_paths.sort();
+
+    let mut fns = vec![];
+
+    for path in module_paths.iter() {
+        let modules = modules.borrow();
+        let module = modules.get(path).unwrap();
+
+        for item in module.items.iter() {
+            let ItemKind::Abi(item_abi) = &item.value else { continue };
+
+            // Get the functions declared in the abi, including the default implementations in its `abi_defs_opt` block
+            let abi_fns = item_abi.abi_items.inner.iter()
+                .filter_map(|abi_item| match &abi_item.value {
+                    ItemTraitItem::Fn(fn_signature, _) => Some((fn_signature, &abi_item.attribute_list)),
+                    _ => None,
+                })
+                .chain(item_abi.abi_defs_opt.iter().flat_map(|abi_defs| {
+                    abi_defs.inner.iter().map(|item_fn| (&item_fn.value.fn_signature, &item_fn.attribute_list))
+                }))
+                .collect::<Vec<_>>();
+
+            for (fn_signature, attribute_list) in abi_fns {
+
+                let arg_tys = match &fn_signature.arguments.inner {
+                    FnArgs::Static(args) => utils::fold_punctuated(args).iter().map(|arg| utils::ty_to_string(&arg.ty)).collect(),
+                    FnArgs::NonStatic { args_opt, .. } => args_opt.as_ref()
+                        .map(|(_, args)| utils::fold_punctuated(args).iter().map(|arg| utils::ty_to_string(&arg.ty)).collect())
+                        .unwrap_or_default(),
+                };
+
+                let return_ty = fn_signature.return_type_opt.as_ref()
+                    .map(|(_, ty)| utils::ty_to_string(ty))
+                    .unwrap_or_else(|| "()".into());
+
+                // Get the arguments of the storage attribute, i.e: `#[storage(read, write)]`
+                let mut storage_access = attribute_list.iter()
+                    .flat_map(|attribute_decl| utils::fold_punctuated(&attribute_decl.attribute.inner))
+                    .filter(|attribute| attribute.name.as_str() == "storage")
+                    .filter_map(|attribute| attribute.args.as_ref())
+                    .flat_map(|args| utils::fold_punctuated(&args.inner).iter().map(|arg| arg.name.as_str().to_string()).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+
+                storage_access.sort();
+                storage_access.dedup();
+
+                fns.push(AbiFnLayout {
+                    path: path.clone(),
+                    line: project.span_to_line(path, &fn_signature.name.span())?,
+                    abi_name: item_abi.name.as_str().to_string(),
+                    name: fn_signature.name.as_str().to_string(),
+                    arg_tys,
+                    return_ty,
+                    storage_access,
+                    is_payable: utils::check_attribute_decls(attribute_list, "payable", &[]),
+                });
+            }
+        }
+    }
+
+    let logged_types: Rc<RefCell<Vec<LoggedTypeLayout>>> = Rc::new(RefCell::new(vec![]));
+    let log_names: Rc<RefCell<HashMap<PathBuf, Vec<String>>>> = Rc::new(RefCell::new(HashMap::new()));
+
+    let mut visitor = AstVisitorRecursive::default();
+
+    // Collect the names `std::logging::log` is imported as, i.e: `use std::logging::log as emit;`
+    visitor.visit_use_hooks.push(Box::new(|context, _scope, _project| {
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::logging::log") {
+            log_names.borrow_mut().entry(context.path.into()).or_default().push(name);
+        }
+
+        Ok(())
+    }));
+
+    // Collect the types of the values passed to `log`, i.e: `log(Transfer { ... })` or `Transfer::new(...).log()`
+    visitor.visit_expr_hooks.push(Box::new(|context, _scope, project| {
+        let logged_value = match context.expr {
+            Expr::FuncApp { func, args } => {
+                let func_name = func.span();
+
+                // Since `std::logging::log` is part of the prelude, always include `log` here
+                let is_log = matches!(func_name.as_str(), "log" | "std::logging::log")
+                    || log_names.borrow().get(context.path).is_some_and(|names| names.iter().any(|name| name == func_name.as_str()));
+
+                if !is_log {
+                    return Ok(());
+                }
+
+                let Some(arg) = utils::fold_punctuated(&args.inner).first().cloned() else { return Ok(()) };
+                arg
+            }
+
+            Expr::MethodCall { target, path_seg, args, .. } if path_seg.name.as_str() == "log" && utils::fold_punctuated(&args.inner).is_empty() => {
+                target.as_ref()
+            }
+
+            _ => return Ok(()),
+        };
+
+        let Some(name) = get_logged_ty_name(logged_value) else { return Ok(()) };
+
+        let mut logged_types = logged_types.borrow_mut();
+
+        if logged_types.iter().any(|x| x.name == name) {
+            return Ok(());
+        }
+
+        logged_types.push(LoggedTypeLayout {
+            path: context.path.into(),
+            line: project.span_to_line(context.path, &context.expr.span())?,
+            name,
+        });
+
+        Ok(())
+    }));
+
+    for path in module_paths {
+        let modules = modules.borrow();
+        let module = modules.get(&path).unwrap();
+
+        let context = ModuleContext {
+            path: &path,
+            module,
+        };
+
+        let scope = Rc::new(RefCell::new(AstScope::default()));
+
+        visitor.visit_module(&context, scope.clone(), project)?;
+        visitor.leave_module(&context, scope.clone(), project)?;
+    }
+
+    let logged_types = logged_types.borrow().clone();
+
+    Ok(AbiLayout { fns, logged_types })
+}
+
+/// Compares the ABI layout of the `old` project against the ABI layout of the `new` project,
+/// adding an entry to the report of the `new` project for each breaking change.
+pub fn diff_abi_layouts(old: &mut Project, new: &mut Project) -> Result<(), Error> {
+    let old_layout = abi_layout(old)?;
+    let new_layout = abi_layout(new)?;
+
+    let mut report = new.report.borrow_mut();
+    let mut renamed_abis: Vec<(&str, &str)> = vec![];
+
+    for old_fn in old_layout.fns.iter() {
+        // Functions are called by their name, so match against a function in any ABI, preferring the ABI with the same name
+        let new_fn = new_layout.fns.iter()
+            .find(|x| x.abi_name == old_fn.abi_name && x.name == old_fn.name)
+            .or_else(|| new_layout.fns.iter().find(|x| x.name == old_fn.name));
+
+        // Check for functions which were removed or renamed
+        let Some(new_fn) = new_fn else {
+            report.add_entry(
+                old_fn.path.clone(),
+                old_fn.line,
+                Severity::High,
+                format!(
+                    "The `{}::{}` function was removed or renamed. Consider keeping the function in order to remain compatible with existing callers.",
+                    old_fn.abi_name,
+                    old_fn.name,
+                ),
+            );
+            continue;
+        };
+
+        // Check for ABIs which were renamed, which only affects integrators referring to the ABI by name
+        if new_fn.abi_name != old_fn.abi_name && !renamed_abis.contains(&(old_fn.abi_name.as_str(), new_fn.abi_name.as_str())) {
+            renamed_abis.push((old_fn.abi_name.as_str(), new_fn.abi_name.as_str()));
+
+            report.add_entry(
+                new_fn.path.clone(),
+                new_fn.line,
+                Severity::Low,
+                format!(
+                    "The `{}` ABI was renamed to `{}`. Consider notifying integrators which refer to the ABI by name.",
+                    old_fn.abi_name,
+                    new_fn.abi_name,
+                ),
+            );
+        }
+
+        // Check for functions whose arguments or return type changed
+        if old_fn.arg_tys != new_fn.arg_tys || old_fn.return_ty != new_fn.return_ty {
+            report.add_entry(
+                new_fn.path.clone(),
+                new_fn.line,
+                Severity::High,
+                format!(
+                    "The signature of the `{}::{}` function was changed from `{}` to `{}`. Consider adding a new function instead of changing the signature of an existing function.",
+                    new_fn.abi_name,
+                    new_fn.name,
+                    old_fn.signature(),
+                    new_fn.signature(),
+                ),
+            );
+        }
+
+        // Check for functions whose storage access changed
+        if old_fn.storage_access != new_fn.storage_access {
+            report.add_entry(
+                new_fn.path.clone(),
+                new_fn.line,
+                Severity::Medium,
+                format!(
+                    "The `{}::{}` function was changed from {} to {}. Consider notifying integrators of the change in storage access.",
+                    new_fn.abi_name,
+                    new_fn.name,
+                    old_fn.storage_attribute(),
+                    new_fn.storage_attribute(),
+                ),
+            );
+        }
+
+        // Check for functions which no longer accept assets
+        if old_fn.is_payable && !new_fn.is_payable {
+            report.add_entry(
+                new_fn.path.clone(),
+                new_fn.line,
+                Severity::High,
+                format!(
+                    "The `{}::{}` function is no longer `#[payable]`, which will cause calls forwarding assets to revert. Consider keeping the `#[payable]` attribute.",
+                    new_fn.abi_name,
+                    new_fn.name,
+                ),
+            );
+        }
+    }
+
+    // Check for types which are no longer logged
+    for old_logged_type in old_layout.logged_types.iter() {
+        if new_layout.logged_types.iter().any(|x| x.name == old_logged_type.name) {
+            continue;
+        }
+
+        report.add_entry(
+            old_logged_type.path.clone(),
+            old_logged_type.line,
+            Severity::Medium,
+            format!(
+                "The `{}` type is no longer logged. Consider notifying integrators which decode the logs of the contract.",
+                old_logged_type.name,
+            ),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,479 @@
+use crate::{
+    error::Error,
+    project::Project,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, AstVisitorRecursive, ExprContext, ModuleContext},
+};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::HashMap, fmt::Write, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, ItemImpl, ItemImplItem, ItemKind, Module, Ty};
+use sway_types::Spanned;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum CallGraphNodeKind {
+    /// A function declared at the top level of a module, i.e: `fn f() {}`
+    Function,
+    /// A function declared in an `impl` block, i.e: `impl MyStruct { fn f() {} }`
+    Method,
+    /// A function declared in an ABI implementation for `Contract`, i.e: `impl MyAbi for Contract { fn f() {} }`
+    AbiMethod,
+    /// A function of another contract called through an ABI, i.e: `abi(MyAbi, id).f()`
+    ExternalCall,
+    /// A function of the standard library or the prelude, i.e: `std::hash::sha256` or `msg_sender`
+    Std,
+    /// A function declared in a dependency of the package, i.e: `ownership::only_owner`
+    Library,
+    /// A method called on a value whose type could not be resolved, i.e: `_::unwrap` for `f().unwrap()`
+    UnresolvedMethod,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CallGraphNode {
+    pub name: String,
+    pub kind: CallGraphNodeKind,
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CallGraphEdge {
+    pub caller: String,
+    pub callee: String,
+}
+
+/// The functions of a package and the calls between them.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CallGraph {
+    pub nodes: Vec<CallGraphNode>,
+    pub edges: Vec<CallGraphEdge>,
+}
+
+impl CallGraph {
+    /// Adds a node to the call graph if a node with the same name has not been added already.
+    pub fn add_node<S: Into<String>>(&mut self, name: S, kind: CallGraphNodeKind, path: Option<PathBuf>, line: Option<usize>) {
+        let name = name.into();
+
+        if self.nodes.iter().any(|x| x.name == name) {
+            return;
+        }
+
+        self.nodes.push(CallGraphNode { name, kind, path, line });
+    }
+
+    /// Adds an edge to the call graph if the same edge has not been added already.
+    pub fn add_edge<S1: Into<String>, S2: Into<String>>(&mut self, caller: S1, callee: S2) {
+        let edge = CallGraphEdge {
+            caller: caller.into(),
+            callee: callee.into(),
+        };
+
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Gets the node with the supplied `name`.
+    pub fn get_node(&self, name: &str) -> Option<&CallGraphNode> {
+        self.nodes.iter().find(|x| x.name == name)
+    }
+
+    /// Writes the call graph in the Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut result = String::new();
+
+        writeln!(result, "digraph call_graph {{").unwrap();
+
+        for node in self.nodes.iter() {
+            let attributes = match node.kind {
+                CallGraphNodeKind::Function | CallGraphNodeKind::Method => "shape=ellipse",
+                CallGraphNodeKind::AbiMethod => "shape=box",
+                CallGraphNodeKind::ExternalCall => "shape=box, style=dashed",
+                CallGraphNodeKind::Std | CallGraphNodeKind::Library => "shape=ellipse, style=dashed",
+                CallGraphNodeKind::UnresolvedMethod => "shape=ellipse, style=dotted",
+            };
+
+            writeln!(result, "    {} [{attributes}];", escape_dot_id(&node.name)).unwrap();
+        }
+
+        for edge in self.edges.iter() {
+            writeln!(result, "    {} -> {};", escape_dot_id(&edge.caller), escape_dot_id(&edge.callee)).unwrap();
+        }
+
+        writeln!(result, "}}").unwrap();
+
+        result
+    }
+}
+
+/// Quotes `name` for use as a Graphviz DOT identifier, i.e: `"Contract::transfer"`
+fn escape_dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Gets the name of the type of `item_impl` without its generic parameters, i.e: `MyStruct` for `impl<T> MyStruct<T>`
+fn get_impl_ty_name(item_impl: &ItemImpl) -> String {
+    match &item_impl.ty {
+        Ty::Path(path_type) => path_type.suffix.last().map(|(_, segment)| segment).unwrap_or(&path_type.prefix).name.as_str().to_string(),
+        ty => ty.span().as_str().to_string(),
+    }
+}
+
+/// Gets the name of the call graph node for the `fn_name` function declared in the module at `module_path`,
+/// i.e: `f`, `MyStruct::f`, `Contract::f` or `my_module::f`
+pub fn get_fn_node_name(module_path: &[String], item_impl: Option<&ItemImpl>, fn_name: &str) -> String {
+    let mut segments = module_path.to_vec();
+
+    if let Some(item_impl) = item_impl {
+        segments.push(get_impl_ty_name(item_impl));
+    }
+
+    segments.push(fn_name.to_string());
+    segments.join("::")
+}
+
+/// Gets the names of the package functions which may be called by `func` from the module at `module_path`, in order of precedence,
+/// i.e: `f`, `my_module::f`, `crate::my_module::f`, `Self::f` or a function imported with `use my_module::f;`
+///
+/// The names are formatted like the names returned by `get_fn_node_name`, and the first name which refers to a function declared
+/// in the package should be used.
+pub fn get_fn_call_candidates(module: &Module, module_path: &[String], item_impl: Option<&ItemImpl>, func: &Expr) -> Vec<String> {
+    let Expr::Path(path) = func else { return vec![] };
+
+    let segments = utils::fold_path_idents(path).iter().map(|x| x.as_str().to_string()).collect::<Vec<_>>();
+
+    get_path_candidates(module, module_path, item_impl, &segments)
+}
+
+/// Gets the names of the package functions which may be called by a method call on a value of type `ty`, in order of precedence,
+/// i.e: `my_module::MyStruct::f` for `x.f()` where `x: MyStruct` and `use my_module::MyStruct;`
+pub fn get_method_call_candidates(module: &Module, module_path: &[String], item_impl: Option<&ItemImpl>, ty: &Ty, method_name: &str) -> Vec<String> {
+    let Some(mut segments) = get_ty_path_segments(ty) else { return vec![] };
+    segments.push(method_name.to_string());
+
+    get_path_candidates(module, module_path, item_impl, &segments)
+}
+
+/// Gets the segments of the path of `ty` without generic arguments, i.e: `["fee", "Fee"]` for `fee::Fee<u64>`
+fn get_ty_path_segments(ty: &Ty) -> Option<Vec<String>> {
+    let Ty::Path(path_type) = ty else { return None };
+
+    Some(
+        std::iter::once(&path_type.prefix)
+            .chain(path_type.suffix.iter().map(|(_, segment)| segment))
+            .map(|segment| segment.name.as_str().to_string())
+            .collect()
+    )
+}
+
+/// Gets the names of the package functions which may be referred to by the path `segments`, in order of precedence
+fn get_path_candidates(module: &Module, module_path: &[String], item_impl: Option<&ItemImpl>, segments: &[String]) -> Vec<String> {
+    if segments.is_empty() {
+        return vec![];
+    }
+
+    // Resolve paths relative to the crate root or the parent module, i.e: `crate::my_module::f` or `super::f`
+    let resolve_relative = |segments: &[String]| match segments.first().map(String::as_str) {
+        Some("crate") => vec![segments[1..].to_vec()],
+        Some("super") => vec![[&module_path[..module_path.len().saturating_sub(1)], &segments[1..]].concat()],
+        _ => vec![[module_path, segments].concat(), segments.to_vec()],
+    };
+
+    // Resolve calls to other functions of the same impl, i.e: `Self::f()`
+    if let (Some(item_impl), "Self") = (item_impl, segments[0].as_str()) {
+        return vec![[module_path, &[get_impl_ty_name(item_impl)][..], &segments[1..]].concat().join("::")];
+    }
+
+    let mut candidates = resolve_relative(segments);
+
+    // Resolve calls through `use` declarations, i.e: `use my_module::f; f()` or `use my_module::*; MyStruct::f()`
+    for item in module.items.iter() {
+        let ItemKind::Use(item_use) = &item.value else { continue };
+
+        for path_expr in utils::flatten_use_tree(None, &item_use.tree) {
+            let use_segments = utils::fold_path_idents(&path_expr).iter().map(|x| x.as_str().to_string()).collect::<Vec<_>>();
+            let (name, use_prefix) = use_segments.split_last().unwrap();
+
+            if name != "*" && *name != segments[0] {
+                continue;
+            }
+
+            let suffix = if name == "*" { &segments[..] } else { &segments[1..] };
+            let mut use_path = use_prefix.to_vec();
+
+            if name != "*" {
+                use_path.push(name.clone());
+            }
+
+            candidates.extend(resolve_relative(&[use_path.as_slice(), suffix].concat()));
+        }
+    }
+
+    candidates.into_iter().map(|x| x.join("::")).collect()
+}
+
+/// Gets the kind of the node for a function which is not declared in the package, resolving the root of its path through
+/// `use` declarations, i.e: `Std` for `std::hash::sha256`, `msg_sender` or `Vec::new`, or `Library` for `ownership::only_owner`
+fn get_external_fn_kind(module: &Module, segments: &[String]) -> CallGraphNodeKind {
+    let Some(root) = segments.first() else { return CallGraphNodeKind::Std };
+
+    // Get the root of the path the first segment was imported from, i.e: `std` for `use std::hash::sha256;`
+    let use_root = module.items.iter()
+        .filter_map(|item| match &item.value {
+            ItemKind::Use(item_use) => Some(utils::flatten_use_tree(None, &item_use.tree)),
+            _ => None,
+        })
+        .flatten()
+        .map(|path_expr| utils::fold_path_idents(&path_expr))
+        .find(|use_idents| use_idents.last().is_some_and(|name| name.as_str() == root.as_str()))
+        .map(|use_idents| use_idents[0].as_str().to_string());
+
+    match use_root.as_deref() {
+        Some("std" | "core") => CallGraphNodeKind::Std,
+        Some(_) => CallGraphNodeKind::Library,
+
+        // Paths which are not imported refer to the prelude, i.e: `msg_sender` or `Vec::new`, unless they start with a crate name
+        None if matches!(root.as_str(), "std" | "core") => CallGraphNodeKind::Std,
+        None if segments.len() == 1 || root.starts_with(|c: char| c.is_ascii_uppercase()) => CallGraphNodeKind::Std,
+        None => CallGraphNodeKind::Library,
+    }
+}
+
+/// Attempts to build the call graph of the functions declared in all of the parsed files of `project`.
+pub fn call_graph(project: &mut Project) -> Result<CallGraph, Error> {
+    let modules = project.modules();
+
+    let mut module_paths = modules.borrow().keys().cloned().collect::<Vec<_>>();
+    module_paths.sort();
+
+    let call_graph = Rc::new(RefCell::new(CallGraph::default()));
+
+    // Add a node for each function declared in the package before resolving any calls
+    for path in module_paths.iter() {
+        let modules = modules.borrow();
+        let module = modules.get(path).unwrap();
+        let module_path = project.module_path(path);
+
+        for item in module.items.iter() {
+            match &item.value {
+                ItemKind::Fn(item_fn) => {
+                    call_graph.borrow_mut().add_node(
+                        get_fn_node_name(&module_path, None, item_fn.fn_signature.name.as_str()),
+                        CallGraphNodeKind::Function,
+                        Some(path.clone()),
+                        project.span_to_line(path, &item_fn.fn_signature.name.span())?,
+                    );
+                }
+
+                ItemKind::Impl(item_impl) => {
+                    let kind = if item_impl.trait_opt.is_some() && item_impl.ty.span().as_str() == "Contract" {
+                        CallGraphNodeKind::AbiMethod
+                    } else {
+                        CallGraphNodeKind::Method
+                    };
+
+                    for impl_item in item_impl.contents.inner.iter() {
+                        let ItemImplItem::Fn(item_fn) = &impl_item.value else { continue };
+
+                        call_graph.borrow_mut().add_node(
+                            get_fn_node_name(&module_path, Some(item_impl), item_fn.fn_signature.name.as_str()),
+                            kind,
+                            Some(path.clone()),
+                            project.span_to_line(path, &item_fn.fn_signature.name.span())?,
+                        );
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    let mut visitor = AstVisitorRecursive::default();
+
+    visitor.visitors.push(Box::new(CallGraphVisitor {
+        call_graph: call_graph.clone(),
+    }));
+
+    for path in module_paths {
+        let modules = modules.borrow();
+        let module = modules.get(&path).unwrap();
+
+        let context = ModuleContext {
+            path: &path,
+            module,
+        };
+
+        let scope = Rc::new(RefCell::new(AstScope::default()));
+
+        visitor.visit_module(&context, scope.clone(), project)?;
+        visitor.leave_module(&context, scope.clone(), project)?;
+    }
+
+    let call_graph = call_graph.borrow().clone();
+
+    Ok(call_graph)
+}
+
+/// Propagates the states of the functions of a package to the functions calling them until nothing changes,
+/// so that the order in which functions are declared does not matter.
+///
+/// The `called_fns` callback gets the names of the functions called by a function, and the `merge` callback
+/// merges the state of a called function into the state of its caller, returning true if anything changed.
+pub fn propagate_fn_states<T, C, M>(fn_states: &mut HashMap<String, T>, called_fns: C, mut merge: M)
+where
+    T: Clone,
+    C: Fn(&T) -> Vec<String>,
+    M: FnMut(&mut T, &T) -> bool,
+{
+    let mut fn_names = fn_states.keys().cloned().collect::<Vec<_>>();
+    fn_names.sort();
+
+    loop {
+        let mut changed = false;
+
+        for fn_name in fn_names.iter() {
+            for called_fn_name in called_fns(fn_states.get(fn_name).unwrap()) {
+                let Some(called_fn_state) = fn_states.get(&called_fn_name).cloned() else { continue };
+                changed |= merge(fn_states.get_mut(fn_name).unwrap(), &called_fn_state);
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+}
+
+/// Adds an edge to the call graph for each function call made by a function of the package.
+pub struct CallGraphVisitor {
+    pub call_graph: Rc<RefCell<CallGraph>>,
+}
+
+impl AstVisitor for CallGraphVisitor {
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check expressions in functions
+        let Some(item_fn) = context.item_fn else { return Ok(()) };
+
+        let module_path = project.module_path(context.path);
+        let caller = get_fn_node_name(&module_path, context.item_impl, item_fn.fn_signature.name.as_str());
+
+        let mut call_graph = self.call_graph.borrow_mut();
+
+        match context.expr {
+            Expr::FuncApp { func, .. } => {
+                let mut func_name = func.span().as_str().to_string();
+
+                // Resolve calls to functions declared in the package, i.e: `f()`, `my_module::f()` or `MyStruct::<T>::f()`
+                let candidates = get_fn_call_candidates(context.module, &module_path, context.item_impl, func.as_ref());
+
+                if let Some(name) = candidates.into_iter().find(|x| call_graph.get_node(x).is_some()) {
+                    func_name = name;
+                }
+
+                if call_graph.get_node(&func_name).is_none() {
+                    // Skip enum variants and tuple struct constructors, i.e: `Identity::Address(x)`
+                    let is_constructor = func_name.rsplit("::").next()
+                        .and_then(|name| name.chars().next())
+                        .map(|c| c.is_ascii_uppercase())
+                        .unwrap_or(false);
+
+                    if is_constructor {
+                        return Ok(());
+                    }
+
+                    let segments = match func.as_ref() {
+                        Expr::Path(path) => utils::fold_path_idents(path).iter().map(|x| x.as_str().to_string()).collect(),
+                        _ => vec![],
+                    };
+
+                    call_graph.add_node(func_name.as_str(), get_external_fn_kind(context.module, &segments), None, None);
+                }
+
+                call_graph.add_edge(caller, func_name);
+            }
+
+            Expr::MethodCall { target, path_seg, .. } => {
+                let method_name = path_seg.name.as_str();
+
+                // Skip storage method calls, i.e: `storage.x.read()`
+                let idents = utils::fold_expr_idents(context.expr);
+
+                if idents.first().map(|x| x.as_str() == "storage").unwrap_or(false) {
+                    return Ok(());
+                }
+
+                let abi_name = match target.as_ref() {
+                    // Check for external calls on abi casts, i.e: `abi(MyAbi, id).f()`
+                    Expr::AbiCast { args, .. } => Some(utils::path_type_to_string(&args.inner.name)),
+
+                    // Check for external calls on abi variables, i.e: `let x = abi(MyAbi, id); x.f()`
+                    Expr::Path(path) if path.suffix.is_empty() && path.prefix.name.as_str() != "self" => {
+                        scope.borrow().get_variable(path.prefix.name.as_str(), false).and_then(|variable| {
+                            let ty_name = utils::ty_to_string(&variable.borrow().ty);
+                            scope.borrow().find_abi(|x| x.borrow().name.as_str() == ty_name).map(|_| ty_name)
+                        })
+                    }
+
+                    _ => None,
+                };
+
+                if let Some(abi_name) = abi_name {
+                    let callee = format!("{abi_name}::{method_name}");
+                    call_graph.add_node(callee.as_str(), CallGraphNodeKind::ExternalCall, None, None);
+                    call_graph.add_edge(caller, callee);
+                    return Ok(());
+                }
+
+                // Check for calls to methods of the same impl, i.e: `self.f()`
+                if let (Some(item_impl), "self") = (context.item_impl, target.span().as_str()) {
+                    let callee = get_fn_node_name(&module_path, Some(item_impl), method_name);
+
+                    if call_graph.get_node(&callee).is_some() {
+                        call_graph.add_edge(caller, callee);
+                    }
+
+                    return Ok(());
+                }
+
+                // Get the type of the value the method is called on, i.e: `MyStruct` for `let x: MyStruct = ...; x.f()`
+                let ty = match target.as_ref() {
+                    Expr::Path(path) if path.suffix.is_empty() => {
+                        scope.borrow().get_variable(path.prefix.name.as_str(), false).map(|variable| variable.borrow().ty.clone())
+                    }
+
+                    _ => None,
+                };
+
+                let ty_segments = ty.as_ref().and_then(get_ty_path_segments);
+
+                let (Some(ty), Some(ty_segments)) = (ty.as_ref(), ty_segments) else {
+                    // Add calls to methods of values whose type could not be resolved as leaf nodes, i.e: `f().unwrap()`
+                    let callee = format!("_::{method_name}");
+                    call_graph.add_node(callee.as_str(), CallGraphNodeKind::UnresolvedMethod, None, None);
+                    call_graph.add_edge(caller, callee);
+                    return Ok(());
+                };
+
+                // Resolve calls to methods declared in the package, i.e: `x.f()` for `x: MyStruct`
+                let candidates = get_method_call_candidates(context.module, &module_path, context.item_impl, ty, method_name);
+
+                let callee = match candidates.into_iter().find(|x| call_graph.get_node(x).is_some()) {
+                    Some(callee) => callee,
+
+                    None => {
+                        let callee = format!("{}::{method_name}", ty_segments.join("::"));
+                        call_graph.add_node(callee.as_str(), get_external_fn_kind(context.module, &ty_segments), None, None);
+                        callee
+                    }
+                };
+
+                call_graph.add_edge(caller, callee);
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
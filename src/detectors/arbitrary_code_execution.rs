@@ -341,7 +341,7 @@ impl AstVisitor for ArbitraryCodeExecutionVisitor {
         }
 
         // Only check `LDC` instructions
-        let "ldc" = context.instruction.op_code_ident().as_str() else { return Ok(()) };
+        let Some(utils::ContractCodeInstruction::Ldc) = utils::get_contract_code_instruction(context.instruction) else { return Ok(()) };
 
         project.report.borrow_mut().add_entry(
             context.path,
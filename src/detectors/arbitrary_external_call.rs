@@ -0,0 +1,263 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::{AstScope, AstVariableKind},
+    utils,
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext, StatementLetContext, UseContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, FnArgs, IfCondition};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct ArbitraryExternalCallVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+struct ModuleState {
+    call_with_function_selector_names: Vec<String>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            call_with_function_selector_names: vec!["std::low_level_call::call_with_function_selector".into()],
+            fn_states: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    caller_supplied_names: Vec<String>,
+    validated_names: Vec<String>,
+}
+
+impl FnState {
+    /// Attempts to find a caller-supplied variable that `expr` is derived from.
+    fn find_caller_supplied_name(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Parens(parens) => self.find_caller_supplied_name(&parens.inner),
+
+            Expr::Path(_) => {
+                let name = expr.span().as_str().to_string();
+                self.caller_supplied_names.contains(&name).then_some(name)
+            }
+
+            Expr::FuncApp { args, .. } => {
+                utils::fold_punctuated(&args.inner).into_iter().find_map(|arg| self.find_caller_supplied_name(arg))
+            }
+
+            Expr::MethodCall { target, .. } |
+            Expr::FieldProjection { target, .. } => self.find_caller_supplied_name(target.as_ref()),
+
+            _ => None,
+        }
+    }
+}
+
+fn expr_mentions_name(expr: &Expr, name: &str) -> bool {
+    expr.span().as_str().split(|c: char| !(c.is_alphanumeric() || c == '_')).any(|x| x == name)
+}
+
+/// Checks if `expr` only consists of constants or configurables, i.e: `ContractId::from(TOKEN_ID)`
+fn is_constant_expr(expr: &Expr, scope: Rc<RefCell<AstScope>>) -> bool {
+    match expr {
+        Expr::Parens(parens) => is_constant_expr(&parens.inner, scope),
+
+        Expr::Path(_) => {
+            let Some(variable) = scope.borrow().get_variable(expr.span().as_str(), false) else { return false };
+            let variable = variable.borrow();
+            matches!(variable.kind, AstVariableKind::Constant | AstVariableKind::Configurable)
+        }
+
+        Expr::FuncApp { args, .. } => {
+            let args = utils::fold_punctuated(&args.inner);
+            !args.is_empty() && args.into_iter().all(|arg| is_constant_expr(arg, scope.clone()))
+        }
+
+        Expr::MethodCall { target, args, .. } => utils::fold_punctuated(&args.inner).is_empty() && is_constant_expr(target.as_ref(), scope),
+
+        _ => false,
+    }
+}
+
+/// Checks if `condition` validates `name` via a membership check, i.e: `storage.allowed.get(target)` or `target == TOKEN_ID`
+fn is_membership_check(condition: &Expr, name: &str, scope: Rc<RefCell<AstScope>>) -> bool {
+    match condition {
+        Expr::Parens(parens) => is_membership_check(&parens.inner, name, scope),
+
+        Expr::LogicalAnd { lhs, rhs, .. } => {
+            is_membership_check(lhs.as_ref(), name, scope.clone()) || is_membership_check(rhs.as_ref(), name, scope)
+        }
+
+        // Every branch of a disjunction needs to perform a membership check
+        Expr::LogicalOr { lhs, rhs, .. } => {
+            is_membership_check(lhs.as_ref(), name, scope.clone()) && is_membership_check(rhs.as_ref(), name, scope)
+        }
+
+        // Check for equality against a constant or configurable, i.e: `target == TOKEN_ID`
+        Expr::Equal { lhs, rhs, .. } => {
+            (expr_mentions_name(lhs.as_ref(), name) && is_constant_expr(rhs.as_ref(), scope.clone()))
+                || (expr_mentions_name(rhs.as_ref(), name) && is_constant_expr(lhs.as_ref(), scope))
+        }
+
+        // Check for a storage map or set lookup, i.e: `storage.allowed.get(target).read()`
+        Expr::MethodCall { .. } => {
+            let idents = utils::fold_expr_idents(condition);
+            idents.len() > 2
+                && idents[0].as_str() == "storage"
+                && idents[2..].iter().any(|ident| matches!(ident.as_str(), "get" | "contains"))
+                && expr_mentions_name(condition, name)
+        }
+
+        _ => false,
+    }
+}
+
+impl AstVisitor for ArbitraryExternalCallVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::low_level_call::call_with_function_selector`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::low_level_call::call_with_function_selector") {
+            module_state.call_with_function_selector_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.entry(fn_signature).or_default();
+
+        // Store the names of the function's parameters
+        let args = match &context.item_fn.fn_signature.arguments.inner {
+            FnArgs::Static(args) => args,
+            FnArgs::NonStatic { args_opt: Some(args), .. } => &args.1,
+            _ => return Ok(()),
+        };
+
+        for arg in args {
+            for ident in utils::fold_pattern_idents(&arg.pattern) {
+                fn_state.caller_supplied_names.push(ident.as_str().to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Only check variables derived from caller-supplied values, i.e: `let id = ContractId::from(target);`
+        let Some(name) = fn_state.find_caller_supplied_name(&context.statement_let.expr) else { return Ok(()) };
+
+        // Variables derived from validated values are also validated
+        let is_validated = fn_state.validated_names.contains(&name);
+
+        for ident in utils::fold_pattern_idents(&context.statement_let.pattern) {
+            fn_state.caller_supplied_names.push(ident.as_str().to_string());
+
+            if is_validated {
+                fn_state.validated_names.push(ident.as_str().to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Check for allow-list validation via `require` or `if/revert`, i.e: `require(storage.allowed.get(target).read(), ...)`
+        let condition = if let Some(args) = utils::get_require_args(context.expr) {
+            args.first().cloned()
+        } else if let Some(IfCondition::Expr(condition)) = utils::get_if_revert_condition(context.expr) {
+            Some(condition.as_ref())
+        } else {
+            None
+        };
+
+        if let Some(condition) = condition {
+            let validated_names = fn_state.caller_supplied_names.iter()
+                .filter(|name| is_membership_check(condition, name, scope.clone()))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            fn_state.validated_names.extend(validated_names);
+
+            return Ok(());
+        }
+
+        // Get the target contract of the external call
+        let target = match context.expr {
+            // Check for `abi(MyAbi, target)`
+            Expr::AbiCast { args, .. } => args.inner.address.as_ref(),
+
+            // Check for `call_with_function_selector(target, ...)`
+            Expr::FuncApp { func, args } if module_state.call_with_function_selector_names.iter().any(|name| func.span().as_str() == name) => {
+                let Some(target) = utils::fold_punctuated(&args.inner).first().cloned() else { return Ok(()) };
+                target
+            }
+
+            _ => return Ok(()),
+        };
+
+        // Check if the target contract is derived from a caller-supplied value without validation
+        let Some(name) = fn_state.find_caller_supplied_name(target) else { return Ok(()) };
+
+        if fn_state.validated_names.contains(&name) {
+            return Ok(());
+        }
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &context.expr.span())?,
+            Severity::High,
+            format!(
+                "{} performs an external call to a caller-supplied contract: `{}`. Consider validating `{}` against an allow-list.",
+                utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                context.expr.span().as_str(),
+                name,
+            ),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_arbitrary_external_call() {
+        crate::tests::test_detector("arbitrary_external_call", 4);
+    }
+}
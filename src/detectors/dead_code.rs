@@ -0,0 +1,446 @@
+use crate::{
+    error::Error,
+    project::{PackageStates, Project},
+    report::Severity,
+    scope::{AstScope, AstVariableKind},
+    utils,
+    visitor::{
+        AbiContext, AstVisitor, ConfigurableFieldContext, ConstContext, EnumFieldContext,
+        ExprContext, FnContext, IfExprContext, ImplContext, MatchBranchContext, ModuleContext,
+        StatementLetContext, StorageFieldContext, StructFieldContext, TypeAliasContext,
+    },
+};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    path::PathBuf,
+    rc::Rc,
+};
+use sway_ast::{
+    ty::TyTupleDescriptor, Expr, FnArgs, FnSignature, IfCondition, ItemKind, ItemTraitItem,
+    PathExpr, PathType, Pattern, Ty,
+};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct DeadCodeVisitor {
+    package_states: PackageStates<PackageState>,
+}
+
+#[derive(Default)]
+struct PackageState {
+    declarations: Vec<Declaration>,
+    used_names: HashSet<String>,
+    constructed_variants: HashSet<String>,
+    used_spans: HashSet<Span>,
+    read_storage_fields: HashSet<String>,
+    storage_call_targets: Vec<Span>,
+}
+
+impl PackageState {
+    /// Checks if `name` refers to a variable in `scope`, keying the usage of constants and configurables by the span of their declaration.
+    /// Local variables and parameters are not declarations which can be dead code, so their usage is not stored.
+    fn check_variable_usage(&mut self, name: &str, scope: &Rc<RefCell<AstScope>>) -> bool {
+        let Some(variable) = scope.borrow().get_variable(name, false) else { return false };
+        let variable = variable.borrow();
+
+        if matches!(variable.kind, AstVariableKind::Constant | AstVariableKind::Configurable) {
+            self.used_spans.insert(variable.span.clone());
+        }
+
+        true
+    }
+
+    fn check_path_expr_usage(&mut self, path: &PathExpr) {
+        self.used_names.insert(path.prefix.name.as_str().to_string());
+
+        if let Some((_, generics)) = path.prefix.generics_opt.as_ref() {
+            for ty in &generics.parameters.inner {
+                self.check_ty_usage(ty);
+            }
+        }
+
+        for (_, segment) in path.suffix.iter() {
+            self.used_names.insert(segment.name.as_str().to_string());
+
+            if let Some((_, generics)) = segment.generics_opt.as_ref() {
+                for ty in &generics.parameters.inner {
+                    self.check_ty_usage(ty);
+                }
+            }
+        }
+    }
+
+    fn check_path_type_usage(&mut self, path: &PathType) {
+        self.used_names.insert(path.prefix.name.as_str().to_string());
+
+        if let Some((_, generics)) = path.prefix.generics_opt.as_ref() {
+            for ty in &generics.parameters.inner {
+                self.check_ty_usage(ty);
+            }
+        }
+
+        for (_, segment) in path.suffix.iter() {
+            self.used_names.insert(segment.name.as_str().to_string());
+        }
+    }
+
+    fn check_ty_usage(&mut self, ty: &Ty) {
+        match ty {
+            Ty::Path(path) => self.check_path_type_usage(path),
+
+            Ty::Tuple(tuple) => {
+                if let TyTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
+                    self.check_ty_usage(head.as_ref());
+
+                    for ty in tail {
+                        self.check_ty_usage(ty);
+                    }
+                }
+            }
+
+            Ty::Array(array) => self.check_ty_usage(&array.inner.ty),
+
+            Ty::Ptr { ty, .. } | Ty::Slice { ty, .. } => self.check_ty_usage(ty.inner.as_ref()),
+
+            Ty::Ref { ty, .. } => self.check_ty_usage(ty.as_ref()),
+
+            _ => {}
+        }
+    }
+
+    fn check_pattern_usage(&mut self, pattern: &Pattern, scope: &Rc<RefCell<AstScope>>) {
+        utils::map_pattern(pattern, &mut |pattern| {
+            match pattern {
+                // Only constants are used by a single identifier pattern, otherwise it declares a new variable binding
+                Pattern::AmbiguousSingleIdent(ident) => {
+                    self.check_variable_usage(ident.as_str(), scope);
+                }
+
+                Pattern::Constant(path) | Pattern::Constructor { path, .. } | Pattern::Struct { path, .. } => {
+                    self.check_path_expr_usage(path);
+                }
+
+                _ => {}
+            }
+        });
+    }
+
+    fn check_fn_signature_usage(&mut self, fn_signature: &FnSignature) {
+        let args = match &fn_signature.arguments.inner {
+            FnArgs::Static(args) => Some(args),
+            FnArgs::NonStatic { args_opt, .. } => args_opt.as_ref().map(|(_, args)| args),
+        };
+
+        for arg in args.into_iter().flatten() {
+            self.check_ty_usage(&arg.ty);
+        }
+
+        if let Some((_, ty)) = fn_signature.return_type_opt.as_ref() {
+            self.check_ty_usage(ty);
+        }
+    }
+}
+
+struct Declaration {
+    path: PathBuf,
+    span: Span,
+    name: String,
+    kind: DeclarationKind,
+}
+
+#[derive(Clone, Copy)]
+enum DeclarationKind {
+    Function,
+    Constant,
+    Struct,
+    EnumVariant,
+    StorageField,
+    Configurable,
+}
+
+impl DeclarationKind {
+    fn description(&self) -> &'static str {
+        match self {
+            DeclarationKind::Function => "function is never called",
+            DeclarationKind::Constant => "constant is never used",
+            DeclarationKind::Struct => "struct is never used",
+            DeclarationKind::EnumVariant => "enum variant is never constructed",
+            DeclarationKind::StorageField => "storage field is never read",
+            DeclarationKind::Configurable => "configurable is never read",
+        }
+    }
+}
+
+impl AstVisitor for DeadCodeVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get or create the package state
+        let package_state = self.package_states.visit_module(project, context.path);
+
+        // Store the declarations of the module ahead of time
+        for item in context.module.items.iter() {
+            let mut declare = |span: Span, name: String, kind: DeclarationKind| {
+                package_state.declarations.push(Declaration {
+                    path: context.path.into(),
+                    span,
+                    name,
+                    kind,
+                });
+            };
+
+            match &item.value {
+                ItemKind::Fn(item_fn) => {
+                    // Skip public functions, entry points and tests
+                    if item_fn.fn_signature.visibility.is_some()
+                        || item_fn.fn_signature.name.as_str() == "main"
+                        || utils::check_attribute_decls(&item.attribute_list, "test", &[])
+                    {
+                        continue;
+                    }
+
+                    declare(item_fn.fn_signature.name.span(), item_fn.fn_signature.name.as_str().into(), DeclarationKind::Function);
+                }
+
+                ItemKind::Const(item_const) if item_const.visibility.is_none() => {
+                    declare(item_const.name.span(), item_const.name.as_str().into(), DeclarationKind::Constant);
+                }
+
+                ItemKind::Struct(item_struct) if item_struct.visibility.is_none() => {
+                    declare(item_struct.name.span(), item_struct.name.as_str().into(), DeclarationKind::Struct);
+                }
+
+                ItemKind::Enum(item_enum) if item_enum.visibility.is_none() => {
+                    for field in &item_enum.fields.inner {
+                        declare(
+                            field.value.name.span(),
+                            format!("{}::{}", item_enum.name.as_str(), field.value.name.as_str()),
+                            DeclarationKind::EnumVariant,
+                        );
+                    }
+                }
+
+                ItemKind::Storage(item_storage) => {
+                    for field in &item_storage.fields.inner {
+                        declare(field.value.name.span(), field.value.name.as_str().into(), DeclarationKind::StorageField);
+                    }
+                }
+
+                ItemKind::Configurable(item_configurable) => {
+                    for field in &item_configurable.fields.inner {
+                        declare(field.value.name.span(), field.value.name.as_str().into(), DeclarationKind::Configurable);
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check the package once all of its modules have been visited
+        let Some(package_state) = self.package_states.leave_module(context.path) else { return Ok(()) };
+
+        for declaration in package_state.declarations.iter() {
+            let is_used = match declaration.kind {
+                DeclarationKind::EnumVariant => package_state.constructed_variants.contains(&declaration.name),
+                DeclarationKind::StorageField => package_state.read_storage_fields.contains(&declaration.name),
+                DeclarationKind::Constant | DeclarationKind::Configurable => {
+                    package_state.used_spans.contains(&declaration.span) || package_state.used_names.contains(&declaration.name)
+                }
+                _ => package_state.used_names.contains(&declaration.name),
+            };
+
+            if is_used {
+                continue;
+            }
+
+            project.report.borrow_mut().add_entry(
+                declaration.path.clone(),
+                project.span_to_line(declaration.path.as_path(), &declaration.span)?,
+                Severity::Low,
+                format!(
+                    "The `{}` {}. Consider removing any dead code.",
+                    declaration.name,
+                    declaration.kind.description(),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn visit_struct_field(&mut self, context: &StructFieldContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_ty_usage(&context.field.ty);
+
+        Ok(())
+    }
+
+    fn visit_enum_field(&mut self, context: &EnumFieldContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_ty_usage(&context.field.ty);
+
+        Ok(())
+    }
+
+    fn visit_abi(&mut self, context: &AbiContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        for item in &context.item_abi.abi_items.inner {
+            if let ItemTraitItem::Fn(fn_signature, _) = &item.value {
+                package_state.check_fn_signature_usage(fn_signature);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_impl(&mut self, context: &ImplContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_ty_usage(&context.item_impl.ty);
+
+        if let Some((path_type, _)) = context.item_impl.trait_opt.as_ref() {
+            package_state.check_path_type_usage(path_type);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_fn_signature_usage(&context.item_fn.fn_signature);
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_pattern_usage(&context.statement_let.pattern, &scope);
+
+        if let Some((_, ty)) = context.statement_let.ty_opt.as_ref() {
+            package_state.check_ty_usage(ty);
+        }
+
+        Ok(())
+    }
+
+    fn visit_if_expr(&mut self, context: &IfExprContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        if let IfCondition::Let { lhs, .. } = &context.if_expr.condition {
+            package_state.check_pattern_usage(lhs.as_ref(), &scope);
+        }
+
+        Ok(())
+    }
+
+    fn visit_match_branch(&mut self, context: &MatchBranchContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_pattern_usage(&context.branch.pattern, &scope);
+
+        Ok(())
+    }
+
+    fn visit_const(&mut self, context: &ConstContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        if let Some((_, ty)) = context.item_const.ty_opt.as_ref() {
+            package_state.check_ty_usage(ty);
+        }
+
+        Ok(())
+    }
+
+    fn visit_storage_field(&mut self, context: &StorageFieldContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_ty_usage(&context.field.ty);
+
+        Ok(())
+    }
+
+    fn visit_configurable_field(&mut self, context: &ConfigurableFieldContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_ty_usage(&context.field.ty);
+
+        Ok(())
+    }
+
+    fn visit_type_alias(&mut self, context: &TypeAliasContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        package_state.check_ty_usage(&context.item_type_alias.ty);
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        let package_state = self.package_states.get_mut(context.path);
+
+        match context.expr {
+            // Check for identifiers, function calls and enum variant construction, i.e: `x`, `f()` and `E::V`
+            Expr::Path(path) => {
+                // Check for identifiers which refer to a variable, constant or configurable, i.e: `x`
+                if path.root_opt.is_none() && path.suffix.is_empty() && package_state.check_variable_usage(path.prefix.name.as_str(), &scope) {
+                    return Ok(());
+                }
+
+                package_state.check_path_expr_usage(path);
+
+                let segments = std::iter::once(&path.prefix)
+                    .chain(path.suffix.iter().map(|(_, segment)| segment))
+                    .map(|segment| segment.name.as_str())
+                    .collect::<Vec<_>>();
+
+                if segments.len() >= 2 {
+                    package_state.constructed_variants.insert(segments[segments.len() - 2..].join("::"));
+                }
+            }
+
+            Expr::Struct { path, .. } => {
+                package_state.check_path_expr_usage(path);
+            }
+
+            Expr::AbiCast { args, .. } => {
+                package_state.check_path_type_usage(&args.inner.name);
+            }
+
+            // Check for storage reads, i.e: `storage.x.read()`
+            Expr::MethodCall { .. } => {
+                if let Some((storage_name, false)) = utils::get_storage_access(context.expr, &mut package_state.storage_call_targets) {
+                    package_state.read_storage_fields.insert(storage_name);
+                }
+            }
+
+            // Check for storage fields which are passed by key, i.e: `f(storage.x)`
+            Expr::FieldProjection { target, name, .. } => {
+                if target.span().as_str() != "storage" || package_state.storage_call_targets.contains(&context.expr.span()) {
+                    return Ok(());
+                }
+
+                package_state.read_storage_fields.insert(name.as_str().to_string());
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_dead_code() {
+        crate::tests::test_detector("dead_code", 7);
+    }
+}
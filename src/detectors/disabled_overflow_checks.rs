@@ -0,0 +1,307 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, BlockContext, ExprContext, FnContext, ModuleContext, StatementLetContext, UseContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{expr::ReassignmentOpVariant, Expr, Pattern};
+use sway_types::{Span, Spanned};
+
+//
+// Sway reverts on arithmetic overflow by default. Calling `disable_panic_on_overflow`
+// (or `set_flags` with arbitrary flags) turns that off until the flags are restored
+// with `enable_panic_on_overflow` or `set_flags(prior)`.
+//
+
+#[derive(Default)]
+pub struct DisabledOverflowChecksVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+struct ModuleState {
+    disable_names: Vec<String>,
+    enable_names: Vec<String>,
+    set_flags_names: Vec<String>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            disable_names: vec!["std::flags::disable_panic_on_overflow".into()],
+            enable_names: vec!["std::flags::enable_panic_on_overflow".into()],
+            set_flags_names: vec!["std::flags::set_flags".into()],
+            fn_states: HashMap::new(),
+        }
+    }
+}
+
+impl ModuleState {
+    fn expr_is_call_to(expr: &Expr, names: &[String]) -> bool {
+        // Destructure the expression into a function application
+        let Expr::FuncApp { func, .. } = expr else { return false };
+        let Expr::Path(path) = func.as_ref() else { return false };
+
+        names.iter().any(|name| path.span().as_str() == name)
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    block_states: HashMap<Span, BlockState>,
+    prior_flags_names: Vec<String>,
+}
+
+#[derive(Clone, Default)]
+struct BlockState {
+    disabled_span: Option<Span>,
+}
+
+impl AstVisitor for DisabledOverflowChecksVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::flags::disable_panic_on_overflow`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::flags::disable_panic_on_overflow") {
+            module_state.disable_names.push(name);
+        }
+
+        // Check the use tree for `std::flags::enable_panic_on_overflow`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::flags::enable_panic_on_overflow") {
+            module_state.enable_names.push(name);
+        }
+
+        // Check the use tree for `std::flags::set_flags`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::flags::set_flags") {
+            module_state.set_flags_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature).or_default();
+
+        Ok(())
+    }
+
+    fn leave_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Get the block state of the function body
+        let Some(block_state) = fn_state.block_states.get(&context.item_fn.body.span()) else { return Ok(()) };
+
+        // Check if overflow panics are still disabled at the end of the function
+        let Some(disabled_span) = block_state.disabled_span.as_ref() else { return Ok(()) };
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, disabled_span)?,
+            Severity::High,
+            format!(
+                "{} disables overflow panics without restoring them before returning: `{}`. Consider calling `enable_panic_on_overflow` or `set_flags` with the prior flags.",
+                utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                disabled_span.as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+
+    fn visit_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Create the block state, which starts with the state of its parent block
+        let block_state = context.blocks.last()
+            .and_then(|parent_span| fn_state.block_states.get(parent_span))
+            .cloned()
+            .unwrap_or_default();
+
+        fn_state.block_states.insert(context.block.span(), block_state);
+
+        Ok(())
+    }
+
+    fn leave_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Get the block state
+        let Some(disabled_span) = fn_state.block_states.get(&context.block.span()).and_then(|x| x.disabled_span.clone()) else { return Ok(()) };
+
+        // Overflow panics are still disabled on at least one path through the parent block
+        // if they are disabled at the end of the block, i.e: `if x { disable_panic_on_overflow(); }`
+        let Some(parent_state) = context.blocks.last().and_then(|parent_span| fn_state.block_states.get_mut(parent_span)) else { return Ok(()) };
+
+        if parent_state.disabled_span.is_none() {
+            parent_state.disabled_span = Some(disabled_span);
+        }
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check single variable patterns
+        let Pattern::AmbiguousSingleIdent(ident) = &context.statement_let.pattern else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Only check variables bound to the prior flags, i.e: `let prior = disable_panic_on_overflow();`
+        if !ModuleState::expr_is_call_to(&context.statement_let.expr, module_state.disable_names.as_slice()) {
+            return Ok(());
+        }
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.prior_flags_names.push(ident.as_str().to_string());
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Get the block state
+        let Some(block_span) = context.blocks.last() else { return Ok(()) };
+        let Some(block_state) = fn_state.block_states.get_mut(block_span) else { return Ok(()) };
+
+        // Check for calls which disable overflow panics
+        if ModuleState::expr_is_call_to(context.expr, module_state.disable_names.as_slice()) {
+            block_state.disabled_span = Some(context.expr.span());
+            return Ok(());
+        }
+
+        // Check for calls which enable overflow panics
+        if ModuleState::expr_is_call_to(context.expr, module_state.enable_names.as_slice()) {
+            block_state.disabled_span = None;
+            return Ok(());
+        }
+
+        // Check for calls to `set_flags`, which restore overflow panics when supplied the prior flags
+        if ModuleState::expr_is_call_to(context.expr, module_state.set_flags_names.as_slice()) {
+            let Expr::FuncApp { args, .. } = context.expr else { return Ok(()) };
+            let args = utils::fold_punctuated(&args.inner);
+
+            let restores_prior_flags = args.len() == 1
+                && fn_state.prior_flags_names.iter().any(|name| args[0].span().as_str() == name);
+
+            block_state.disabled_span = if restores_prior_flags {
+                None
+            } else {
+                Some(context.expr.span())
+            };
+
+            return Ok(());
+        }
+
+        // Everything else only matters while overflow panics are disabled
+        if block_state.disabled_span.is_none() {
+            return Ok(());
+        }
+
+        match context.expr {
+            Expr::Return { .. } => {
+                project.report.borrow_mut().add_entry(
+                    context.path,
+                    project.span_to_line(context.path, &context.expr.span())?,
+                    Severity::High,
+                    format!(
+                        "{} returns while overflow panics are disabled: `{}`. Consider restoring them before returning.",
+                        utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                        context.expr.span().as_str(),
+                    ),
+                );
+            }
+
+            Expr::Add { .. }
+            | Expr::Sub { .. }
+            | Expr::Mul { .. }
+            | Expr::Pow { .. }
+            | Expr::Shl { .. } => {
+                project.report.borrow_mut().add_entry(
+                    context.path,
+                    project.span_to_line(context.path, &context.expr.span())?,
+                    Severity::Medium,
+                    format!(
+                        "{} performs arithmetic while overflow panics are disabled: `{}`. Consider checking the result for overflow manually.",
+                        utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                        context.expr.span().as_str(),
+                    ),
+                );
+            }
+
+            Expr::Reassignment { reassignment_op, .. } if matches!(
+                reassignment_op.variant,
+                ReassignmentOpVariant::AddEquals
+                | ReassignmentOpVariant::SubEquals
+                | ReassignmentOpVariant::MulEquals
+                | ReassignmentOpVariant::ShlEquals
+            ) => {
+                project.report.borrow_mut().add_entry(
+                    context.path,
+                    project.span_to_line(context.path, &context.expr.span())?,
+                    Severity::Medium,
+                    format!(
+                        "{} performs arithmetic while overflow panics are disabled: `{}`. Consider checking the result for overflow manually.",
+                        utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                        context.expr.span().as_str(),
+                    ),
+                );
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_disabled_overflow_checks() {
+        crate::tests::test_detector("disabled_overflow_checks", 7);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ModuleContext, StatementContext, UseContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, ItemImplItem, ItemKind, ItemTraitItem, Statement};
+use sway_types::Spanned;
+
+#[derive(Default)]
+pub struct DiscardedReturnValueVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    std_fn_paths: HashMap<String, String>,
+}
+
+/// The `std` functions whose returned values should always be handled.
+const CHECKED_STD_FN_PATHS: &[&str] = &[
+    "std::auth::caller_address",
+    "std::ecr::ec_recover",
+    "std::ecr::ec_recover_address",
+    "std::inputs::input_coin_owner",
+];
+
+/// The storage methods whose returned values should always be handled.
+const CHECKED_STORAGE_FN_NAMES: &[&str] = &[
+    "try_read",
+];
+
+/// Gets the name of the returned type without its generic arguments, since `std` signatures are declared in terms of type parameters.
+fn get_checked_ty_name(ty_name: &str) -> Option<&str> {
+    let ty_name = ty_name.split('<').next().unwrap().trim();
+
+    matches!(ty_name, "bool" | "Option" | "Result").then_some(ty_name)
+}
+
+/// Attempts to get the return type of a top-level function declared in a `std` library module.
+fn get_std_fn_return_ty_name(project: &Project, module_name: &str, fn_name: &str) -> Option<String> {
+    let resolver = project.resolver.borrow();
+    let library = resolver.libraries.iter().find(|library| library.name == "std")?;
+    let module = library.modules.iter().find(|module| module.name == module_name)?;
+
+    for item in module.inner.items.iter() {
+        let ItemKind::Fn(item_fn) = &item.value else { continue };
+
+        if item_fn.fn_signature.name.as_str() == fn_name {
+            return Some(
+                item_fn.fn_signature.return_type_opt.as_ref()
+                    .map(|(_, ty)| utils::ty_to_string(ty))
+                    .unwrap_or_else(|| "()".into())
+            );
+        }
+    }
+
+    None
+}
+
+/// Attempts to get the return type of a function declared in an `impl` for a `std` type starting with `impl_ty_prefix`.
+fn get_std_impl_fn_return_ty_name(project: &Project, impl_ty_prefix: &str, fn_name: &str) -> Option<String> {
+    let resolver = project.resolver.borrow();
+    let library = resolver.libraries.iter().find(|library| library.name == "std")?;
+
+    for module in library.modules.iter() {
+        for item in module.inner.items.iter() {
+            let ItemKind::Impl(item_impl) = &item.value else { continue };
+
+            if !item_impl.ty.span().as_str().starts_with(impl_ty_prefix) {
+                continue;
+            }
+
+            for impl_item in item_impl.contents.inner.iter() {
+                let ItemImplItem::Fn(item_fn) = &impl_item.value else { continue };
+
+                if item_fn.fn_signature.name.as_str() == fn_name {
+                    return Some(
+                        item_fn.fn_signature.return_type_opt.as_ref()
+                            .map(|(_, ty)| utils::ty_to_string(ty))
+                            .unwrap_or_else(|| "()".into())
+                    );
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Attempts to get the return type of an `abi` function declared in the current scope.
+fn get_abi_fn_return_ty_name(scope: Rc<RefCell<AstScope>>, abi_name: &str, fn_name: &str) -> Option<String> {
+    let abi_name = abi_name.rsplit("::").next().unwrap_or(abi_name);
+    let item_abi = scope.borrow().find_abi(|x| x.borrow().name.as_str() == abi_name)?;
+    let item_abi = item_abi.borrow();
+
+    for item in item_abi.abi_items.inner.iter() {
+        let ItemTraitItem::Fn(fn_signature, _) = &item.value else { continue };
+
+        if fn_signature.name.as_str() == fn_name {
+            return Some(
+                fn_signature.return_type_opt.as_ref()
+                    .map(|(_, ty)| utils::ty_to_string(ty))
+                    .unwrap_or_else(|| "()".into())
+            );
+        }
+    }
+
+    None
+}
+
+impl AstVisitor for DiscardedReturnValueVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Store the full paths of symbols imported from `std`
+        for path_expr in utils::flatten_use_tree(None, &context.item_use.tree) {
+            let path = utils::path_expr_to_string(&path_expr);
+
+            if !path.starts_with("std::") {
+                continue;
+            }
+
+            let name = path.rsplit("::").next().unwrap().to_string();
+            module_state.std_fn_paths.insert(name, path);
+        }
+
+        Ok(())
+    }
+
+    fn visit_statement(&mut self, context: &StatementContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check expression statements
+        let Statement::Expr { expr, .. } = context.statement else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        let ty_name = match expr {
+            // Check for calls to `std` functions, i.e: `ec_recover(signature, msg_hash);`
+            Expr::FuncApp { func, .. } => {
+                let func_name = func.span().as_str().to_string();
+
+                let Some(path) = (if func_name.starts_with("std::") {
+                    Some(func_name)
+                } else {
+                    module_state.std_fn_paths.get(&func_name).cloned()
+                }) else { return Ok(()) };
+
+                if !CHECKED_STD_FN_PATHS.contains(&path.as_str()) {
+                    return Ok(());
+                }
+
+                let parts = path.split("::").collect::<Vec<_>>();
+
+                if parts.len() < 3 {
+                    return Ok(());
+                }
+
+                get_std_fn_return_ty_name(project, parts[1..parts.len() - 1].join("::").as_str(), parts[parts.len() - 1])
+            }
+
+            Expr::MethodCall { target, path_seg, .. } => {
+                let idents = utils::fold_expr_idents(expr);
+
+                match target.as_ref() {
+                    // Check for external calls on abi casts, i.e: `abi(MyAbi, id).f();`
+                    Expr::AbiCast { args, .. } => {
+                        let abi_name = utils::path_type_to_string(&args.inner.name);
+                        get_abi_fn_return_ty_name(scope, abi_name.as_str(), path_seg.name.as_str())
+                    }
+
+                    // Check for external calls on abi variables, i.e: `let x = abi(MyAbi, id); x.f();`
+                    Expr::Path(_) if idents.len() == 2 => {
+                        let Some(variable) = scope.borrow().get_variable(idents[0].as_str(), false) else { return Ok(()) };
+                        let abi_name = utils::ty_to_string(&variable.borrow().ty);
+                        get_abi_fn_return_ty_name(scope, abi_name.as_str(), path_seg.name.as_str())
+                    }
+
+                    // Check for fallible storage reads, i.e: `storage.x.try_read();`
+                    _ if idents.len() >= 3 && idents[0].as_str() == "storage" && CHECKED_STORAGE_FN_NAMES.contains(&path_seg.name.as_str()) => {
+                        get_std_impl_fn_return_ty_name(project, "StorageKey<T>", path_seg.name.as_str())
+                    }
+
+                    _ => None,
+                }
+            }
+
+            _ => None,
+        };
+
+        let Some(ty_name) = ty_name else { return Ok(()) };
+
+        let Some(ty_name) = get_checked_ty_name(ty_name.as_str()) else { return Ok(()) };
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &expr.span())?,
+            Severity::Medium,
+            format!(
+                "{} discards the `{}` value returned by `{}`. Consider handling the returned value.",
+                utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                ty_name,
+                expr.span().as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_discarded_return_value() {
+        crate::tests::test_detector("discarded_return_value", 7);
+    }
+}
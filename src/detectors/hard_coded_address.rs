@@ -0,0 +1,136 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ExprContext, ModuleContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, ItemKind};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct HardCodedAddressVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    hex_constant_names: Vec<String>,
+    reported_spans: Vec<Span>,
+}
+
+const ADDRESS_CONSTRUCTORS: &[&str] = &[
+    "Address::from",
+    "ContractId::from",
+    "AssetId::from",
+    "Identity::Address",
+    "Identity::ContractId",
+];
+
+/// Checks if `expr` is a non-zero hexadecimal integer literal, i.e: `0x1234...`
+fn is_hex_literal(expr: &Expr) -> bool {
+    let Some(value) = utils::get_int_literal_str(expr) else { return false };
+
+    value.starts_with("0x") && value[2..].chars().any(|c| c != '0' && c != '_')
+}
+
+/// Checks if `func` refers to an address constructor, i.e: `Address::from` or `std::identity::Identity::Address`
+fn is_address_constructor(func: &Expr) -> bool {
+    let func = func.span();
+    let func = func.as_str();
+
+    ADDRESS_CONSTRUCTORS.iter().any(|x| func == *x || func.ends_with(format!("::{x}").as_str()))
+}
+
+impl ModuleState {
+    /// Checks if `expr` is a hard-coded value that can be used as an address, i.e: `0x1234...`, `OWNER` or `Address::from(0x1234...)`
+    fn is_hard_coded_value(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Literal(_) => is_hex_literal(expr),
+
+            Expr::Path(path) if path.root_opt.is_none() && path.suffix.is_empty() => {
+                self.hex_constant_names.iter().any(|name| name == path.prefix.name.as_str())
+            }
+
+            Expr::FuncApp { func, args } if is_address_constructor(func) => {
+                let args = utils::fold_punctuated(&args.inner);
+                args.len() == 1 && self.is_hard_coded_value(args[0])
+            }
+
+            Expr::Parens(parens) => self.is_hard_coded_value(parens.inner.as_ref()),
+
+            _ => false,
+        }
+    }
+}
+
+impl AstVisitor for HardCodedAddressVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        let module_state = self.module_states.entry(context.path.into()).or_default();
+
+        // Store the names of module-level constants which are hexadecimal literals ahead of time, i.e: `const OWNER: b256 = 0x1234...;`
+        for item in context.module.items.iter() {
+            let ItemKind::Const(item_const) = &item.value else { continue };
+
+            if item_const.expr_opt.as_ref().map(is_hex_literal).unwrap_or(false) {
+                module_state.hex_constant_names.push(item_const.name.as_str().to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Skip configurable initializers, since that is where addresses should be declared
+        if matches!(context.item, ItemKind::Configurable(_)) {
+            return Ok(());
+        }
+
+        // Only check address constructors, i.e: `Address::from(...)`
+        let Expr::FuncApp { func, .. } = context.expr else { return Ok(()) };
+
+        if !is_address_constructor(func) {
+            return Ok(());
+        }
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Skip constructors nested in an already reported constructor, i.e: `Identity::Address(Address::from(0x1234...))`
+        let expr_span = context.expr.span();
+
+        if module_state.reported_spans.iter().any(|span| span.start() <= expr_span.start() && expr_span.end() <= span.end()) {
+            return Ok(());
+        }
+
+        if !module_state.is_hard_coded_value(context.expr) {
+            return Ok(());
+        }
+
+        module_state.reported_spans.push(expr_span.clone());
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &expr_span)?,
+            Severity::Low,
+            format!(
+                "{} contains a hard-coded address: `{}`. Consider moving it to a `configurable` block.",
+                utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                expr_span.as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_hard_coded_address() {
+        crate::tests::test_detector("hard_coded_address", 5);
+    }
+}
@@ -7,7 +7,6 @@ use crate::{
     visitor::{AstVisitor, ExprContext},
 };
 use std::{cell::RefCell, rc::Rc};
-use sway_ast::{Expr, Literal};
 use sway_types::Spanned;
 
 #[derive(Default)]
@@ -15,9 +14,7 @@ pub struct LargeLiteralVisitor;
 
 impl AstVisitor for LargeLiteralVisitor {
     fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
-        let Expr::Literal(Literal::Int(i)) = context.expr else { return Ok(()) };
-
-        let value = i.span.as_str();
+        let Some(value) = utils::get_int_literal_str(context.expr) else { return Ok(()) };
 
         if value.starts_with("0x") || value.contains('_') || value.len() <= 6 {
             return Ok(());
@@ -1,10 +1,15 @@
 mod arbitrary_asset_transfer;
 mod arbitrary_code_execution;
+mod arbitrary_external_call;
 mod boolean_comparison;
+mod dead_code;
+mod disabled_overflow_checks;
 mod discarded_assignment;
+mod discarded_return_value;
 mod division_before_multiplication;
 mod explicit_return_statement;
 mod external_call_in_loop;
+mod hard_coded_address;
 mod inline_assembly_usage;
 mod large_literal;
 mod locked_native_asset;
@@ -12,33 +17,51 @@ mod magic_number;
 mod manipulatable_balance_usage;
 mod missing_logs;
 mod msg_amount_in_loop;
+mod narrowing_conversion;
 mod non_zero_identity_validation;
 mod potential_infinite_loop;
 mod redundant_comparison;
 mod redundant_storage_access;
+mod src14_conformance;
+mod src20_conformance;
+mod src5_ownership;
 mod storage_field_mutability;
+mod storage_key_collision;
 mod storage_not_updated;
 mod storage_read_in_loop_condition;
 mod strict_equality;
+mod timestamp_unit_confusion;
+mod unbounded_storage_iteration;
 mod unchecked_call_payload;
+mod unchecked_divisor;
+mod unchecked_unwrap;
+mod unnecessary_storage_permission;
 mod unprotected_initialization;
 mod unprotected_storage_variable;
+mod unreachable_code;
 mod unsafe_timestamp_usage;
 mod unused_import;
+mod unused_parameter;
+mod variable_shadowing;
 mod weak_prng;
 
 use crate::visitor::AstVisitor;
 
 use self::{
-    arbitrary_asset_transfer::*, arbitrary_code_execution::*, boolean_comparison::*,
-    discarded_assignment::*, division_before_multiplication::*, explicit_return_statement::*,
-    external_call_in_loop::*, inline_assembly_usage::*, large_literal::*, locked_native_asset::*,
-    magic_number::*, manipulatable_balance_usage::*, missing_logs::*, msg_amount_in_loop::*,
-    non_zero_identity_validation::*, potential_infinite_loop::*, redundant_comparison::*,
-    redundant_storage_access::*, storage_field_mutability::*, storage_not_updated::*,
-    storage_read_in_loop_condition::*, strict_equality::*, unchecked_call_payload::*,
-    unprotected_initialization::*, unprotected_storage_variable::*, unsafe_timestamp_usage::*,
-    unused_import::*, weak_prng::*,
+    arbitrary_asset_transfer::*, arbitrary_code_execution::*, arbitrary_external_call::*,
+    boolean_comparison::*, dead_code::*, disabled_overflow_checks::*, discarded_assignment::*,
+    discarded_return_value::*, division_before_multiplication::*, explicit_return_statement::*,
+    external_call_in_loop::*, hard_coded_address::*, inline_assembly_usage::*, large_literal::*,
+    locked_native_asset::*, magic_number::*, manipulatable_balance_usage::*, missing_logs::*,
+    msg_amount_in_loop::*, narrowing_conversion::*, non_zero_identity_validation::*,
+    potential_infinite_loop::*, redundant_comparison::*, redundant_storage_access::*,
+    src14_conformance::*, src20_conformance::*, src5_ownership::*, storage_field_mutability::*,
+    storage_key_collision::*, storage_not_updated::*, storage_read_in_loop_condition::*,
+    strict_equality::*, timestamp_unit_confusion::*, unbounded_storage_iteration::*,
+    unchecked_call_payload::*, unchecked_divisor::*, unchecked_unwrap::*,
+    unnecessary_storage_permission::*, unprotected_initialization::*,
+    unprotected_storage_variable::*, unreachable_code::*, unsafe_timestamp_usage::*,
+    unused_import::*, unused_parameter::*, variable_shadowing::*, weak_prng::*,
 };
 
 type DetectorConstructor = fn() -> Box<dyn AstVisitor>;
@@ -47,11 +70,16 @@ type DetectorEntry = (&'static str, DetectorConstructor);
 pub const DETECTOR_TYPES: &[DetectorEntry] = &[
     ("arbitrary_asset_transfer", || Box::<ArbitraryAssetTransferVisitor>::default()),
     ("arbitrary_code_execution", || Box::<ArbitraryCodeExecutionVisitor>::default()),
+    ("arbitrary_external_call", || Box::<ArbitraryExternalCallVisitor>::default()),
     ("boolean_comparison", || Box::<BooleanComparisonVisitor>::default()),
+    ("dead_code", || Box::<DeadCodeVisitor>::default()),
+    ("disabled_overflow_checks", || Box::<DisabledOverflowChecksVisitor>::default()),
     ("discarded_assignment", || Box::<DiscardedAssignmentVisitor>::default()),
+    ("discarded_return_value", || Box::<DiscardedReturnValueVisitor>::default()),
     ("division_before_multiplication", || Box::<DivisionBeforeMultiplicationVisitor>::default()),
     ("explicit_return_statement", || Box::<ExplicitReturnStatementVisitor>::default()),
     ("external_call_in_loop", || Box::<ExternalCallInLoopVisitor>::default()),
+    ("hard_coded_address", || Box::<HardCodedAddressVisitor>::default()),
     ("inline_assembly_usage", || Box::<InlineAssemblyUsageVisitor>::default()),
     ("large_literal", || Box::<LargeLiteralVisitor>::default()),
     ("locked_native_asset", || Box::<LockedNativeAssetVisitor>::default()),
@@ -59,18 +87,31 @@ pub const DETECTOR_TYPES: &[DetectorEntry] = &[
     ("manipulatable_balance_usage", || Box::<ManipulatableBalanceUsageVisitor>::default()),
     ("missing_logs", || Box::<MissingLogsVisitor>::default()),
     ("msg_amount_in_loop", || Box::<MsgAmountInLoopVisitor>::default()),
+    ("narrowing_conversion", || Box::<NarrowingConversionVisitor>::default()),
     ("non_zero_identity_validation", || Box::<NonZeroIdentityValidationVisitor>::default()),
     ("potential_infinite_loop", || Box::<PotentialInfiniteLoopVisitor>::default()),
     ("redundant_comparison", || Box::<RedundantComparisonVisitor>::default()),
     ("redundant_storage_access", || Box::<RedundantStorageAccessVisitor>::default()),
+    ("src14_conformance", || Box::<Src14ConformanceVisitor>::default()),
+    ("src20_conformance", || Box::<Src20ConformanceVisitor>::default()),
+    ("src5_ownership", || Box::<Src5OwnershipVisitor>::default()),
     ("storage_field_mutability", || Box::<StorageFieldMutabilityVisitor>::default()),
+    ("storage_key_collision", || Box::<StorageKeyCollisionVisitor>::default()),
     ("storage_not_updated", || Box::<StorageNotUpdatedVisitor>::default()),
     ("storage_read_in_loop_condition", || Box::<StorageReadInLoopConditionVisitor>::default()),
     ("strict_equality", || Box::<StrictEqualityVisitor>::default()),
+    ("timestamp_unit_confusion", || Box::<TimestampUnitConfusionVisitor>::default()),
+    ("unbounded_storage_iteration", || Box::<UnboundedStorageIterationVisitor>::default()),
     ("unchecked_call_payload", || Box::<UncheckedCallPayloadVisitor>::default()),
+    ("unchecked_divisor", || Box::<UncheckedDivisorVisitor>::default()),
+    ("unchecked_unwrap", || Box::<UncheckedUnwrapVisitor>::default()),
+    ("unnecessary_storage_permission", || Box::<UnnecessaryStoragePermissionVisitor>::default()),
     ("unprotected_initialization", || Box::<UnprotectedInitializationVisitor>::default()),
     ("unprotected_storage_variable", || Box::<UnprotectedStorageVariableVisitor>::default()),
+    ("unreachable_code", || Box::<UnreachableCodeVisitor>::default()),
     ("unsafe_timestamp_usage", || Box::<UnsafeTimestampUsageVisitor>::default()),
     ("unused_import", || Box::<UnusedImportVisitor>::default()),
+    ("unused_parameter", || Box::<UnusedParameterVisitor>::default()),
+    ("variable_shadowing", || Box::<VariableShadowingVisitor>::default()),
     ("weak_prng", || Box::<WeakPrngVisitor>::default()),
 ];
@@ -0,0 +1,275 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils::{self, ValueSource, VarSources},
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext, StatementLetContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, Pattern, Statement, StatementLet, Ty};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct NarrowingConversionVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    fn_states: HashMap<Span, FnState>,
+}
+
+#[derive(Default)]
+struct FnState {
+    var_sources: VarSources,
+}
+
+fn source_description(source: ValueSource) -> Option<&'static str> {
+    match source {
+        ValueSource::Parameter => Some("a user-controlled value"),
+        ValueSource::Arithmetic => Some(source.description()),
+        _ => None,
+    }
+}
+
+fn classify_expr(fn_state: &FnState, expr: &Expr, blocks: &[Span], scope: Rc<RefCell<AstScope>>) -> Option<ValueSource> {
+    fn_state.var_sources.classify_expr(expr, blocks, scope, &|expr| match expr {
+        Expr::Add { .. }
+        | Expr::Sub { .. }
+        | Expr::Mul { .. }
+        | Expr::Div { .. }
+        | Expr::Pow { .. }
+        | Expr::Modulo { .. }
+        | Expr::Shl { .. } => Some(ValueSource::Arithmetic),
+
+        _ => None,
+    })
+}
+
+/// Only attempts to infer types for expressions that `AstScope::get_expr_ty` is able to resolve.
+fn get_expr_ty(expr: &Expr, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Option<Ty> {
+    match expr {
+        Expr::Parens(parens) => get_expr_ty(&parens.inner, scope, project),
+
+        Expr::Path(path) => {
+            if path.root_opt.is_some() || !path.suffix.is_empty() {
+                return None;
+            }
+
+            scope.borrow().get_variable(path.prefix.name.as_str(), false)?;
+            Some(scope.borrow().get_expr_ty(expr, project))
+        }
+
+        Expr::Add { lhs, rhs, .. }
+        | Expr::Sub { lhs, rhs, .. }
+        | Expr::Mul { lhs, rhs, .. }
+        | Expr::Div { lhs, rhs, .. }
+        | Expr::Pow { lhs, rhs, .. }
+        | Expr::Modulo { lhs, rhs, .. }
+        | Expr::Shl { lhs, rhs, .. } => {
+            // Prefer the non-literal operand, since literals are always inferred as `u64`
+            if matches!(lhs.as_ref(), Expr::Literal(_)) {
+                get_expr_ty(rhs.as_ref(), scope, project)
+            } else {
+                get_expr_ty(lhs.as_ref(), scope, project)
+            }
+        }
+
+        _ => None,
+    }
+}
+
+fn get_ty_bits(ty_name: &str) -> Option<usize> {
+    match ty_name {
+        "u8" => Some(8),
+        "u16" => Some(16),
+        "u32" => Some(32),
+        "u64" => Some(64),
+        "u256" | "b256" => Some(256),
+        _ => None,
+    }
+}
+
+/// Gets the value which a byte sequence was built from, i.e: `x` in `x.to_be_bytes()` or `Bytes::from(x)`
+fn get_bytes_source(expr: &Expr) -> Option<&Expr> {
+    match expr {
+        Expr::Parens(parens) => get_bytes_source(&parens.inner),
+
+        Expr::MethodCall { target, path_seg, .. } if matches!(path_seg.name.as_str(), "to_be_bytes" | "to_le_bytes") => {
+            Some(target.as_ref())
+        }
+
+        Expr::FuncApp { func, args } if func.span().as_str() == "Bytes::from" => {
+            let args = utils::fold_punctuated(&args.inner);
+            if args.len() != 1 {
+                return None;
+            }
+
+            Some(args[0])
+        }
+
+        _ => None,
+    }
+}
+
+/// Destructures a conversion expression into its converted value and destination type name (if known).
+fn get_conversion<'a>(expr: &'a Expr, statement: Option<&'a Statement>) -> Option<(&'a Expr, Option<String>)> {
+    // Check for integers built from the bytes of another value, i.e: `u64::from_be_bytes(x.to_be_bytes())`
+    if let Expr::FuncApp { func, args } = expr {
+        let func_name = func.span().as_str().to_string();
+        let ty_name = func_name.strip_suffix("::from_be_bytes").or_else(|| func_name.strip_suffix("::from_le_bytes"))?;
+        get_ty_bits(ty_name)?;
+
+        let args = utils::fold_punctuated(&args.inner);
+        if args.len() != 1 {
+            return None;
+        }
+
+        return Some((get_bytes_source(args[0])?, Some(ty_name.to_string())));
+    }
+
+    let Expr::MethodCall { target, path_seg, .. } = expr else { return None };
+
+    match path_seg.name.as_str() {
+        // Check for `x.as_u8()`, `x.as_u16()`, `x.as_u32()` and `x.as_u64()`
+        "as_u8" | "as_u16" | "as_u32" | "as_u64" => {
+            Some((target.as_ref(), Some(path_seg.name.as_str().trim_start_matches("as_").to_string())))
+        }
+
+        "unwrap" | "expect" => match target.as_ref() {
+            // Check for `x.try_into().unwrap()`
+            Expr::MethodCall { target, path_seg, .. } if path_seg.name.as_str() == "try_into" => {
+                // Get the destination type from the variable binding if available, i.e: `let x: u64 = y.try_into().unwrap();`
+                let ty_name = match statement {
+                    Some(Statement::Let(StatementLet { ty_opt: Some((_, ty)), expr: let_expr, .. })) if let_expr.span() == expr.span() => {
+                        Some(utils::ty_to_string(ty))
+                    }
+
+                    _ => None,
+                };
+
+                Some((target.as_ref(), ty_name))
+            }
+
+            // Check for `u64::try_from(x).unwrap()`
+            Expr::FuncApp { func, args } => {
+                let func_name = func.span().as_str().to_string();
+                let ty_name = func_name.strip_suffix("::try_from")?;
+                get_ty_bits(ty_name)?;
+
+                let args = utils::fold_punctuated(&args.inner);
+                if args.len() != 1 {
+                    return None;
+                }
+
+                Some((args[0], Some(ty_name.to_string())))
+            }
+
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
+impl AstVisitor for NarrowingConversionVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature).or_default();
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check single variable patterns
+        let Pattern::AmbiguousSingleIdent(ident) = &context.statement_let.pattern else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Determine where the variable's value comes from
+        let source = classify_expr(fn_state, &context.statement_let.expr, context.blocks.as_slice(), scope);
+
+        // Store the variable's source in the current block
+        let block_span = context.blocks.last().unwrap();
+
+        fn_state.var_sources.add_var(block_span, ident.as_str(), source, false);
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check conversion expressions
+        let Some((value, to_ty_name)) = get_conversion(context.expr, context.statement) else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Only check values derived from user input or arithmetic results
+        let Some(source) = classify_expr(fn_state, value, context.blocks.as_slice(), scope.clone()) else { return Ok(()) };
+        let Some(source_description) = source_description(source) else { return Ok(()) };
+
+        // Get the type of the converted value
+        let Some(from_ty) = get_expr_ty(value, scope, project) else { return Ok(()) };
+        let from_ty_name = utils::ty_to_string(&from_ty);
+        let Some(from_bits) = get_ty_bits(from_ty_name.as_str()) else { return Ok(()) };
+
+        // Check if the conversion is narrowing
+        match to_ty_name.as_ref().and_then(|to_ty_name| get_ty_bits(to_ty_name)) {
+            Some(to_bits) if from_bits <= to_bits => return Ok(()),
+
+            // Without a known destination type, only conversions from 256-bit values are guaranteed to narrow
+            None if from_bits < 256 => return Ok(()),
+
+            _ => {}
+        }
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &context.expr.span())?,
+            Severity::Medium,
+            format!(
+                "{} contains a narrowing conversion from `{}` to {} on {}: `{}`. Consider checking the value's bounds before converting.",
+                utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                from_ty_name,
+                to_ty_name.map(|x| format!("`{x}`")).unwrap_or_else(|| "a smaller type".into()),
+                source_description,
+                context.expr.span().as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_narrowing_conversion() {
+        crate::tests::test_detector("narrowing_conversion", 5);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     report::Severity,
     scope::AstScope,
     utils,
-    visitor::{AstVisitor, BlockContext, ExprContext, FnContext, ModuleContext, WhileExprContext},
+    visitor::{AstVisitor, BlockContext, ExprContext, FnContext, ForExprContext, ModuleContext, WhileExprContext},
 };
 use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
 use sway_ast::{
@@ -24,142 +24,60 @@ struct ModuleState {
     fn_states: HashMap<Span, FnState>,
 }
 
+/// Tracks the loops of a function, along with whether they can be exited.
 #[derive(Default)]
-struct FnState {
-    block_states: HashMap<Span, BlockState>,
+pub struct LoopAnalysis {
+    loop_states: HashMap<Span, LoopState>,
 }
 
 #[derive(Default)]
-struct BlockState {
-    is_while_loop: bool,
-    has_break: bool,
-    condition: Option<Expr>,
-    condition_updated: bool,
+pub struct LoopState {
+    pub condition: Option<Expr>,
+    pub has_break: bool,
+    pub condition_updated: bool,
 }
 
-impl AstVisitor for PotentialInfiniteLoopVisitor {
-    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
-        // Create the module state
-        if !self.module_states.contains_key(context.path) {
-            self.module_states.insert(context.path.into(), ModuleState::default());
-        }
-
-        Ok(())
-    }
-
-    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-
-        // Create the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-        
-        module_state.fn_states.entry(fn_signature).or_default();
-        
-        Ok(())
+impl LoopState {
+    /// Checks if the loop is a `while` loop which is never exited.
+    pub fn is_potentially_infinite(&self) -> bool {
+        self.condition.is_some() && !self.has_break && !self.condition_updated
     }
+}
 
-    fn visit_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-
-        // Get the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
-
-        // Create the block state
-        let block_span = context.block.span();
-
-        fn_state.block_states.entry(block_span).or_default();
-        
-        Ok(())
+impl LoopAnalysis {
+    /// Gets the state of the loop whose body is the block at `block_span`.
+    pub fn get_loop_state(&self, block_span: &Span) -> Option<&LoopState> {
+        self.loop_states.get(block_span)
     }
 
-    fn leave_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get(context.path).unwrap();
-
-        // Get the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
-
-        // Get the block state
-        let block_span = context.block.span();
-        let block_state = fn_state.block_states.get(&block_span).unwrap();
-
-        if block_state.is_while_loop && (!block_state.has_break && !block_state.condition_updated) {
-            project.report.borrow_mut().add_entry(
-                context.path,
-                project.span_to_line(context.path, &block_span)?,
-                Severity::High,
-                format!(
-                    "{} contains a potentially infinite loop: `while {} {{ ... }}`. Consider adding a `break` statement.",
-                    utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
-                    block_state.condition.as_ref().unwrap().span().as_str(),
-                ),
-            );
-        }
-
-        Ok(())
+    pub fn visit_while_expr(&mut self, context: &WhileExprContext) {
+        // Create the while expression's body loop state and store its condition
+        self.loop_states.insert(context.body.span(), LoopState {
+            condition: Some(context.condition.clone()),
+            ..Default::default()
+        });
     }
 
-    fn visit_while_expr(&mut self, context: &WhileExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-        
-        // Get the function state
-        let fn_signature = context.item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
-
-        // Create the while expression's body block ahead of time
-        let block_span = context.body.span();
-        let block_state = fn_state.block_states.entry(block_span).or_default();
-
-        // Mark the block as a loop and store its condition
-        block_state.is_while_loop = true;
-        block_state.condition = Some(context.condition.clone());
-
-        Ok(())
+    pub fn visit_for_expr(&mut self, context: &ForExprContext) {
+        // Create the for expression's body loop state, so that `break` expressions in its body are not attributed to outer loops
+        self.loop_states.insert(context.body.span(), LoopState::default());
     }
 
-    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
-        // Get the module state
-        let module_state = self.module_states.get_mut(context.path).unwrap();
-        
-        // Get the function state
-        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
-        let fn_signature = item_fn.fn_signature.span();
-        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
-
+    pub fn visit_expr(&mut self, context: &ExprContext) {
         match context.expr {
             Expr::Break { .. } => {
-                for block_span in context.blocks.iter().rev() {
-                    let block_state = fn_state.block_states.get_mut(block_span).unwrap();
-
-                    if block_state.is_while_loop {
-                        block_state.has_break = true;
-                        break;
-                    }
-                }
+                // Mark the innermost loop as having a break
+                let Some(loop_block_span) = context.blocks.iter().rev().find(|x| self.loop_states.contains_key(*x)) else { return };
+                self.loop_states.get_mut(loop_block_span).unwrap().has_break = true;
             }
 
             Expr::Reassignment { assignable, reassignment_op, expr: value } => {
-                let mut loop_block_span = None;
-
-                for block_span in context.blocks.iter().rev() {
-                    let block_state = fn_state.block_states.get_mut(block_span).unwrap();
-
-                    if block_state.is_while_loop {
-                        loop_block_span = Some(block_span);
-                        break;
-                    }
-                }
-
-                let Some(loop_block_span) = loop_block_span else { return Ok(()) };
-                let loop_block_state = fn_state.block_states.get_mut(loop_block_span).unwrap();
+                // Get the innermost while loop
+                let Some(loop_block_span) = context.blocks.iter().rev().find(|x| self.loop_states.get(*x).map(|x| x.condition.is_some()).unwrap_or(false)) else { return };
+                let loop_block_state = self.loop_states.get_mut(loop_block_span).unwrap();
 
                 if loop_block_state.condition_updated {
-                    return Ok(());
+                    return;
                 }
 
                 match loop_block_state.condition.as_ref().unwrap() {
@@ -170,12 +88,12 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
 
                     expr if matches!(expr, Expr::Path(_)) => {
                         // Check if `expr` is set to `false`
-                        let ReassignmentOpVariant::Equals = &reassignment_op.variant else { return Ok(()) };
-                        let Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::False, .. })) = value.as_ref() else { return Ok(()) };
+                        let ReassignmentOpVariant::Equals = &reassignment_op.variant else { return };
+                        let Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::False, .. })) = value.as_ref() else { return };
                         let assignable_idents = utils::fold_assignable_idents(assignable);
                         let condition_idents = utils::fold_expr_idents(expr);
                         if assignable_idents.iter().zip(condition_idents).any(|(a, b)| a.as_str() != b.as_str()) {
-                            return Ok(());
+                            return;
                         }
                         loop_block_state.condition_updated = true;
                     }
@@ -184,19 +102,19 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
                         // Don't check function applications or method calls
                         if matches!(expr.as_ref(), Expr::FuncApp { .. } | Expr::MethodCall { .. }) {
                             loop_block_state.condition_updated = true;
-                            return Ok(());
+                            return;
                         }
                         // Check if `expr` is set to `false` or `true` (depending on the final negation logic)
-                        let ReassignmentOpVariant::Equals = &reassignment_op.variant else { return Ok(()) };
+                        let ReassignmentOpVariant::Equals = &reassignment_op.variant else { return };
                         if utils::expr_negation_result(expr) {
-                            let Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::True, .. })) = value.as_ref() else { return Ok(()) };
+                            let Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::True, .. })) = value.as_ref() else { return };
                         } else {
-                            let Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::False, .. })) = value.as_ref() else { return Ok(()) };
+                            let Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::False, .. })) = value.as_ref() else { return };
                         }
                         let assignable_idents = utils::fold_assignable_idents(assignable);
                         let condition_idents = utils::fold_expr_idents(expr);
                         if assignable_idents.iter().zip(condition_idents).any(|(a, b)| a.as_str() != b.as_str()) {
-                            return Ok(());
+                            return;
                         }
                         loop_block_state.condition_updated = true;
                     }
@@ -205,7 +123,7 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
                         // Don't check function applications or method calls
                         if matches!(lhs.as_ref(), Expr::FuncApp { .. } | Expr::MethodCall { .. }) || matches!(rhs.as_ref(), Expr::FuncApp { .. } | Expr::MethodCall { .. }) {
                             loop_block_state.condition_updated = true;
-                            return Ok(());
+                            return;
                         }
 
                         let assignable_idents = utils::fold_assignable_idents(assignable);
@@ -238,7 +156,7 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
                                 let assignable_idents = utils::fold_assignable_idents(assignable);
                                 let condition_idents = utils::fold_expr_idents(lhs);
                                 if assignable_idents.iter().zip(condition_idents).any(|(a, b)| a.as_str() != b.as_str()) {
-                                    return Ok(());
+                                    return;
                                 }
                                 loop_block_state.condition_updated = true;
                             }
@@ -247,7 +165,7 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
                                 let assignable_idents = utils::fold_assignable_idents(assignable);
                                 let condition_idents = utils::fold_expr_idents(rhs);
                                 if assignable_idents.iter().zip(condition_idents).any(|(a, b)| a.as_str() != b.as_str()) {
-                                    return Ok(());
+                                    return;
                                 }
                                 loop_block_state.condition_updated = true;
                             }
@@ -277,7 +195,7 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
                                 let assignable_idents = utils::fold_assignable_idents(assignable);
                                 let condition_idents = utils::fold_expr_idents(rhs);
                                 if assignable_idents.iter().zip(condition_idents).any(|(a, b)| a.as_str() != b.as_str()) {
-                                    return Ok(());
+                                    return;
                                 }
                                 loop_block_state.condition_updated = true;
                             }
@@ -286,7 +204,7 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
                                 let assignable_idents = utils::fold_assignable_idents(assignable);
                                 let condition_idents = utils::fold_expr_idents(lhs);
                                 if assignable_idents.iter().zip(condition_idents).any(|(a, b)| a.as_str() != b.as_str()) {
-                                    return Ok(());
+                                    return;
                                 }
                                 loop_block_state.condition_updated = true;
                             }
@@ -301,6 +219,100 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
 
             _ => {}
         }
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    loops: LoopAnalysis,
+}
+
+impl AstVisitor for PotentialInfiniteLoopVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        
+        module_state.fn_states.entry(fn_signature).or_default();
+        
+        Ok(())
+    }
+
+    fn leave_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Get the loop state
+        let block_span = context.block.span();
+        let Some(loop_state) = fn_state.loops.get_loop_state(&block_span) else { return Ok(()) };
+
+        if loop_state.is_potentially_infinite() {
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &block_span)?,
+                Severity::High,
+                format!(
+                    "{} contains a potentially infinite loop: `while {} {{ ... }}`. Consider adding a `break` statement.",
+                    utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                    loop_state.condition.as_ref().unwrap().span().as_str(),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn visit_while_expr(&mut self, context: &WhileExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.loops.visit_while_expr(context);
+
+        Ok(())
+    }
+
+    fn visit_for_expr(&mut self, context: &ForExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.loops.visit_for_expr(context);
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.loops.visit_expr(context);
 
         Ok(())
     }
@@ -310,6 +322,6 @@ impl AstVisitor for PotentialInfiniteLoopVisitor {
 mod tests {
     #[test]
     fn test_potential_infinite_loop() {
-        crate::tests::test_detector("potential_infinite_loop", 3);
+        crate::tests::test_detector("potential_infinite_loop", 4);
     }
 }
@@ -0,0 +1,367 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils::{self, ContractCodeInstruction},
+    visitor::{
+        AsmInstructionContext, AstVisitor, ExprContext, FnContext, ModuleContext,
+        StatementLetContext, StorageContext, UseContext,
+    },
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, IfCondition, ItemImplItem, ItemKind};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct Src14ConformanceVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+struct ModuleState {
+    msg_sender_names: Vec<String>,
+    run_external_names: Vec<String>,
+    storage_fields: Vec<StorageFieldState>,
+    fallback_fn_signature: Option<Span>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            // Since `std::auth::msg_sender` is part of the prelude, include it here
+            msg_sender_names: vec!["msg_sender".into()],
+            run_external_names: vec![],
+            storage_fields: vec![],
+            fallback_fn_signature: None,
+            fn_states: Default::default(),
+        }
+    }
+}
+
+impl ModuleState {
+    /// Checks if `expr` contains a call to `msg_sender()` or a variable bound to it.
+    fn expr_contains_msg_sender(&self, fn_state: &FnState, expr: &Expr) -> bool {
+        let mut result = false;
+
+        utils::map_expr(expr, &mut |expr| match expr {
+            Expr::FuncApp { func, .. } => {
+                let func = func.span();
+
+                if func.as_str() == "std::auth::msg_sender" || self.msg_sender_names.iter().any(|name| func.as_str() == name) {
+                    result = true;
+                }
+            }
+
+            Expr::Path(_) => {
+                if fn_state.msg_sender_var_names.iter().any(|name| expr.span().as_str() == name) {
+                    result = true;
+                }
+            }
+
+            _ => {}
+        });
+
+        result
+    }
+}
+
+struct StorageFieldState {
+    name: String,
+    span: Span,
+    has_dedicated_slot: bool,
+}
+
+#[derive(Default)]
+struct FnState {
+    msg_sender_var_names: Vec<String>,
+    has_access_check: bool,
+    has_forward: bool,
+    call_instruction: Option<Span>,
+    storage_reads: Vec<String>,
+    storage_writes: Vec<String>,
+    storage_call_targets: Vec<Span>,
+}
+
+/// Checks if `func` refers to an ownership guard, i.e: `only_owner` or `only_proxy_owner`
+fn is_ownership_guard(func: &Expr) -> bool {
+    let func = func.span();
+
+    matches!(func.as_str().rsplit("::").next(), Some("only_owner" | "only_proxy_owner"))
+}
+
+impl AstVisitor for Src14ConformanceVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::auth::msg_sender`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::auth::msg_sender") {
+            module_state.msg_sender_names.push(name);
+        }
+
+        // Check the use tree for `std::execution::run_external`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::execution::run_external") {
+            module_state.run_external_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_storage(&mut self, context: &StorageContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Fields of a namespaced storage block are stored in dedicated storage slots, i.e: `#[namespace(proxy)]`
+        let has_namespace = utils::get_storage_namespace(context.attributes).is_some();
+
+        // Store the storage fields and whether they are declared in a dedicated storage slot, i.e: `target in 0x...: ContractId`
+        for field in utils::fold_punctuated(&context.item_storage.fields.inner) {
+            module_state.storage_fields.push(StorageFieldState {
+                name: field.value.name.as_str().to_string(),
+                span: field.value.name.span(),
+                has_dedicated_slot: has_namespace || field.value.key_expr.is_some(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature.clone()).or_default();
+
+        // Check if the function is the contract's fallback function, i.e: `#[fallback] fn fallback() { ... }`
+        if context.item_impl.is_none() && utils::check_attribute_decls(context.fn_attributes, "fallback", &[]) {
+            module_state.fallback_fn_signature = Some(fn_signature);
+        }
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Check if the variable stores `msg_sender()`
+        let idents = utils::fold_pattern_idents(&context.statement_let.pattern);
+
+        if idents.len() != 1 || !module_state.expr_contains_msg_sender(fn_state, &context.statement_let.expr) {
+            return Ok(());
+        }
+
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+        fn_state.msg_sender_var_names.push(idents[0].as_str().to_string());
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let Some(fn_state) = module_state.fn_states.get(&fn_signature) else { return Ok(()) };
+
+        // Check for a `require` or `if`-`revert` which checks against `msg_sender()`
+        let condition = if let Some(require_args) = utils::get_require_args(context.expr) {
+            require_args.first().cloned()
+        } else if let Some(IfCondition::Expr(expr)) = utils::get_if_revert_condition(context.expr) {
+            Some(expr.as_ref())
+        } else {
+            None
+        };
+
+        let has_access_check = condition.map(|expr| module_state.expr_contains_msg_sender(fn_state, expr)).unwrap_or(false);
+
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        if has_access_check {
+            fn_state.has_access_check = true;
+            return Ok(());
+        }
+
+        match context.expr {
+            // Check for ownership guards and calls forwarding to the proxy target, i.e: `only_owner()` or `run_external(target)`
+            Expr::FuncApp { func, .. } => {
+                if is_ownership_guard(func) {
+                    fn_state.has_access_check = true;
+                }
+
+                let func = func.span();
+
+                if func.as_str() == "std::execution::run_external" || module_state.run_external_names.iter().any(|name| func.as_str() == name) {
+                    fn_state.has_forward = true;
+                }
+            }
+
+            // Check for storage method calls, i.e: `storage.target.write(new_target)`
+            Expr::MethodCall { .. } => {
+                let Some((storage_name, writes)) = utils::get_storage_access(context.expr, &mut fn_state.storage_call_targets) else { return Ok(()) };
+
+                let storage_names = if writes {
+                    &mut fn_state.storage_writes
+                } else {
+                    &mut fn_state.storage_reads
+                };
+
+                if !storage_names.contains(&storage_name) {
+                    storage_names.push(storage_name);
+                }
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn visit_asm_instruction(&mut self, context: &AsmInstructionContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Check for `LDC` instructions which load the target's code and `CALL` instructions which do not forward the call context
+        match utils::get_contract_code_instruction(context.instruction) {
+            Some(ContractCodeInstruction::Ldc) => fn_state.has_forward = true,
+            Some(ContractCodeInstruction::Call) => fn_state.call_instruction = Some(context.instruction.span()),
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Find the implementation of the SRC-14 proxy standard, i.e: `impl SRC14 for Contract`
+        let Some(item_impl) = context.module.items.iter().find_map(|item| {
+            let ItemKind::Impl(item_impl) = &item.value else { return None };
+            let (path_type, _) = item_impl.trait_opt.as_ref()?;
+            (path_type.span().as_str().rsplit("::").next() == Some("SRC14")).then_some(item_impl)
+        }) else { return Ok(()) };
+
+        let mut proxy_storage_names: Vec<String> = vec![];
+
+        for item in item_impl.contents.inner.iter() {
+            let ItemImplItem::Fn(item_fn) = &item.value else { continue };
+            let fn_signature = item_fn.fn_signature.span();
+            let Some(fn_state) = module_state.fn_states.get(&fn_signature) else { continue };
+
+            // Collect the storage fields used by the proxy
+            for name in fn_state.storage_reads.iter().chain(fn_state.storage_writes.iter()) {
+                if !proxy_storage_names.contains(name) {
+                    proxy_storage_names.push(name.clone());
+                }
+            }
+
+            // Check if `set_proxy_target` is restricted to the proxy owner
+            if item_fn.fn_signature.name.as_str() == "set_proxy_target" && !fn_state.has_access_check {
+                project.report.borrow_mut().add_entry(
+                    context.path,
+                    project.span_to_line(context.path, &fn_signature)?,
+                    Severity::High,
+                    format!(
+                        "The `{}::set_proxy_target` function can change the proxy target without access restriction. Consider checking against `msg_sender()` in order to limit access to the proxy owner.",
+                        item_impl.ty.span().as_str(),
+                    ),
+                );
+            }
+        }
+
+        // Check if the storage fields used by the proxy are declared in dedicated storage slots
+        for storage_field in module_state.storage_fields.iter() {
+            if storage_field.has_dedicated_slot || !proxy_storage_names.contains(&storage_field.name) {
+                continue;
+            }
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &storage_field.span)?,
+                Severity::High,
+                format!(
+                    "The `{}` storage field is used by the SRC-14 proxy without a dedicated storage slot, which can collide with the storage of the proxy target. Consider declaring it with an explicit storage key, i.e: `{} in 0x...`, or in a `#[namespace(...)]` storage block.",
+                    storage_field.name,
+                    storage_field.name,
+                ),
+            );
+        }
+
+        // Check if the fallback function forwards calls to the proxy target
+        let Some(fallback_fn_signature) = module_state.fallback_fn_signature.as_ref() else {
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &item_impl.span())?,
+                Severity::High,
+                "The SRC-14 proxy does not declare a `#[fallback]` function. Consider forwarding calls to the proxy target using `run_external`.".to_string(),
+            );
+
+            return Ok(());
+        };
+
+        let fallback_fn_state = module_state.fn_states.get(fallback_fn_signature).unwrap();
+
+        if let Some(call_instruction) = fallback_fn_state.call_instruction.as_ref() {
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, call_instruction)?,
+                Severity::High,
+                format!(
+                    "The `fallback` function forwards calls using the `CALL` instruction: `{}`. Consider using `run_external` in order to execute the proxy target's code in the context of the proxy.",
+                    call_instruction.as_str(),
+                ),
+            );
+        } else if !fallback_fn_state.has_forward {
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, fallback_fn_signature)?,
+                Severity::High,
+                "The `fallback` function does not forward calls to the proxy target. Consider forwarding calls using `run_external`.".to_string(),
+            );
+        } else if !proxy_storage_names.is_empty() && !proxy_storage_names.iter().any(|name| fallback_fn_state.storage_reads.contains(name)) {
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, fallback_fn_signature)?,
+                Severity::High,
+                format!(
+                    "The `fallback` function does not forward calls to the target stored in the `{}` storage field of the proxy. Consider forwarding calls to the proxy target.",
+                    proxy_storage_names.join("`, `"),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_src14_conformance() {
+        crate::tests::test_detector("src14_conformance", 3);
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{
+    call_graph,
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext, UseContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, FnArgs, ItemFn, ItemImpl, ItemImplItem, ItemKind};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct Src20ConformanceVisitor {
+    module_states: Rc<RefCell<HashMap<PathBuf, ModuleState>>>,
+}
+
+struct ModuleState {
+    log_names: Vec<String>,
+    module_path: Vec<String>,
+    fn_states: HashMap<String, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            // Since `std::logging::log` is part of the prelude, include it here
+            log_names: vec!["log".into()],
+            module_path: vec![],
+            fn_states: Default::default(),
+        }
+    }
+}
+
+impl ModuleState {
+    /// Gets the state of the function named `name`, which is declared in `item_impl` if supplied.
+    fn get_fn_state(&self, item_impl: Option<&ItemImpl>, name: &str) -> Option<&FnState> {
+        self.fn_states.get(&call_graph::get_fn_node_name(&self.module_path, item_impl, name))
+    }
+}
+
+#[derive(Clone, Default)]
+struct FnState {
+    storage_reads: Vec<String>,
+    storage_writes: Vec<String>,
+    logged_events: Vec<String>,
+    storage_call_targets: Vec<Span>,
+    fn_call_candidates: Vec<Vec<String>>,
+    called_fns: Vec<String>,
+}
+
+/// A function required by a standard ABI, where each argument lists the accepted type names.
+struct StandardFn {
+    name: &'static str,
+    args: &'static [&'static [&'static str]],
+    return_ty: &'static str,
+    signature: &'static str,
+}
+
+const SRC20_FNS: &[StandardFn] = &[
+    StandardFn { name: "total_assets", args: &[], return_ty: "u64", signature: "fn total_assets() -> u64" },
+    StandardFn { name: "total_supply", args: &[&["AssetId"]], return_ty: "Option<u64>", signature: "fn total_supply(asset: AssetId) -> Option<u64>" },
+    StandardFn { name: "name", args: &[&["AssetId"]], return_ty: "Option<String>", signature: "fn name(asset: AssetId) -> Option<String>" },
+    StandardFn { name: "symbol", args: &[&["AssetId"]], return_ty: "Option<String>", signature: "fn symbol(asset: AssetId) -> Option<String>" },
+    StandardFn { name: "decimals", args: &[&["AssetId"]], return_ty: "Option<u8>", signature: "fn decimals(asset: AssetId) -> Option<u8>" },
+];
+
+const SRC3_FNS: &[StandardFn] = &[
+    StandardFn { name: "mint", args: &[&["Identity"], &["Option<SubId>", "SubId", "b256"], &["u64"]], return_ty: "()", signature: "fn mint(recipient: Identity, sub_id: Option<SubId>, amount: u64)" },
+    StandardFn { name: "burn", args: &[&["SubId", "b256"], &["u64"]], return_ty: "()", signature: "fn burn(sub_id: SubId, amount: u64)" },
+];
+
+/// The SRC-20 metadata functions and the events which should be logged when their values change.
+const SRC20_METADATA_EVENTS: &[(&str, &str)] = &[
+    ("name", "SetNameEvent"),
+    ("symbol", "SetSymbolEvent"),
+    ("decimals", "SetDecimalsEvent"),
+];
+
+const SRC20_EVENTS: &[&str] = &["TotalSupplyEvent", "SetNameEvent", "SetSymbolEvent", "SetDecimalsEvent"];
+
+/// Attempts to find the implementation of the `trait_name` ABI for `Contract` in `items`.
+fn find_contract_impl<'a>(items: impl Iterator<Item = &'a ItemKind>, trait_name: &str) -> Option<&'a ItemImpl> {
+    items
+        .filter_map(|item| match item {
+            ItemKind::Impl(item_impl) if item_impl.ty.span().as_str() == "Contract" => Some(item_impl),
+            _ => None,
+        })
+        .find(|item_impl| {
+            item_impl.trait_opt.as_ref()
+                .map(|(path_type, _)| path_type.span().as_str().rsplit("::").next() == Some(trait_name))
+                .unwrap_or(false)
+        })
+}
+
+/// Attempts to find the function named `name` in `item_impl`.
+fn find_impl_fn<'a>(item_impl: &'a ItemImpl, name: &str) -> Option<&'a ItemFn> {
+    item_impl.contents.inner.iter().find_map(|item| match &item.value {
+        ItemImplItem::Fn(item_fn) if item_fn.fn_signature.name.as_str() == name => Some(item_fn),
+        _ => None,
+    })
+}
+
+/// Checks if the signature of `item_fn` matches the signature of `standard_fn`.
+fn fn_matches_standard(item_fn: &ItemFn, standard_fn: &StandardFn) -> bool {
+    let args = match &item_fn.fn_signature.arguments.inner {
+        FnArgs::Static(args) => utils::fold_punctuated(args),
+        FnArgs::NonStatic { .. } => return false,
+    };
+
+    if args.len() != standard_fn.args.len() {
+        return false;
+    }
+
+    for (arg, ty_names) in args.iter().zip(standard_fn.args.iter()) {
+        if !ty_names.contains(&utils::ty_to_string(&arg.ty).as_str()) {
+            return false;
+        }
+    }
+
+    let return_ty = item_fn.fn_signature.return_type_opt.as_ref()
+        .map(|(_, ty)| utils::ty_to_string(ty))
+        .unwrap_or_else(|| "()".into());
+
+    return_ty == standard_fn.return_ty
+}
+
+impl AstVisitor for Src20ConformanceVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        let mut module_states = self.module_states.borrow_mut();
+        let module_state = module_states.entry(context.path.into()).or_default();
+
+        module_state.module_path = project.module_path(context.path);
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let mut module_states = self.module_states.borrow_mut();
+        let module_state = module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::logging::log`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::logging::log") {
+            module_state.log_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let mut module_states = self.module_states.borrow_mut();
+        let module_state = module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_name = call_graph::get_fn_node_name(&module_state.module_path, context.item_impl, context.item_fn.fn_signature.name.as_str());
+
+        module_state.fn_states.entry(fn_name).or_default();
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let mut module_states = self.module_states.borrow_mut();
+        let module_state = module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_name = call_graph::get_fn_node_name(&module_state.module_path, context.item_impl, item_fn.fn_signature.name.as_str());
+        let Some(fn_state) = module_state.fn_states.get_mut(&fn_name) else { return Ok(()) };
+
+        // Get the logged value, i.e: `log(TotalSupplyEvent { ... })` or `TotalSupplyEvent::new(...).log()`
+        let logged_value = match context.expr {
+            Expr::FuncApp { func, args } if module_state.log_names.iter().any(|name| name == func.span().as_str()) => {
+                utils::fold_punctuated(&args.inner).first().map(|arg| arg.span())
+            }
+
+            Expr::MethodCall { target, path_seg, args, .. } if path_seg.name.as_str() == "log" && utils::fold_punctuated(&args.inner).is_empty() => {
+                Some(target.span())
+            }
+
+            _ => None,
+        };
+
+        if let Some(logged_value) = logged_value {
+            for event_name in SRC20_EVENTS {
+                if logged_value.as_str().contains(event_name) && !fn_state.logged_events.iter().any(|x| x == event_name) {
+                    fn_state.logged_events.push(event_name.to_string());
+                }
+            }
+
+            return Ok(());
+        }
+
+        // Store the functions which may be called, so that their states can be propagated to this function
+        if let Expr::FuncApp { func, .. } = context.expr {
+            let candidates = call_graph::get_fn_call_candidates(context.module, &module_state.module_path, context.item_impl, func.as_ref());
+
+            if !candidates.is_empty() {
+                fn_state.fn_call_candidates.push(candidates);
+            }
+
+            return Ok(());
+        }
+
+        // Only check storage method calls, i.e: `storage.total_supply.insert(asset, supply)`
+        let Some((storage_name, writes)) = utils::get_storage_access(context.expr, &mut fn_state.storage_call_targets) else { return Ok(()) };
+
+        let storage_names = if writes {
+            &mut fn_state.storage_writes
+        } else {
+            &mut fn_state.storage_reads
+        };
+
+        if !storage_names.contains(&storage_name) {
+            storage_names.push(storage_name);
+        }
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let mut module_states = self.module_states.borrow_mut();
+        let module_state = module_states.get_mut(context.path).unwrap();
+
+        // Resolve the functions called by each function of the module
+        let fn_names = module_state.fn_states.keys().cloned().collect::<Vec<_>>();
+
+        for fn_state in module_state.fn_states.values_mut() {
+            fn_state.called_fns = fn_state.fn_call_candidates.iter()
+                .filter_map(|candidates| candidates.iter().find(|x| fn_names.contains(x)).cloned())
+                .collect();
+        }
+
+        // Propagate the storage accesses and logged events of called functions to the functions calling them
+        call_graph::propagate_fn_states(
+            &mut module_state.fn_states,
+            |fn_state| fn_state.called_fns.clone(),
+            |fn_state, called_fn_state| {
+                let mut changed = false;
+
+                for (names, called_names) in [
+                    (&mut fn_state.storage_reads, &called_fn_state.storage_reads),
+                    (&mut fn_state.storage_writes, &called_fn_state.storage_writes),
+                    (&mut fn_state.logged_events, &called_fn_state.logged_events),
+                ] {
+                    for name in called_names {
+                        if !names.contains(name) {
+                            names.push(name.clone());
+                            changed = true;
+                        }
+                    }
+                }
+
+                changed
+            },
+        );
+
+        let module_state = &*module_state;
+
+        let items = || context.module.items.iter().map(|item| &item.value);
+
+        let src20_impl = find_contract_impl(items(), "SRC20");
+        let src3_impl = find_contract_impl(items(), "SRC3");
+
+        // Check the SRC-20 and SRC-3 implementations for missing or non-conforming functions
+        for (standard_name, item_impl, standard_fns) in [("SRC20", src20_impl, SRC20_FNS), ("SRC3", src3_impl, SRC3_FNS)] {
+            let Some(item_impl) = item_impl else { continue };
+
+            for standard_fn in standard_fns {
+                let Some(item_fn) = find_impl_fn(item_impl, standard_fn.name) else {
+                    project.report.borrow_mut().add_entry(
+                        context.path,
+                        project.span_to_line(context.path, &item_impl.span())?,
+                        Severity::Medium,
+                        format!(
+                            "The `{standard_name}` implementation for `Contract` is missing the `{}` function. Consider implementing the standard completely.",
+                            standard_fn.signature,
+                        ),
+                    );
+                    continue;
+                };
+
+                if !fn_matches_standard(item_fn, standard_fn) {
+                    project.report.borrow_mut().add_entry(
+                        context.path,
+                        project.span_to_line(context.path, &item_fn.fn_signature.span())?,
+                        Severity::Medium,
+                        format!(
+                            "The `Contract::{}` function does not match the `{standard_name}` standard: `{}`. Consider updating the function signature.",
+                            standard_fn.name,
+                            standard_fn.signature,
+                        ),
+                    );
+                }
+            }
+        }
+
+        let get_fn_state = |item_impl: Option<&ItemImpl>, name: &str| {
+            item_impl
+                .and_then(|item_impl| find_impl_fn(item_impl, name).map(|_| item_impl))
+                .and_then(|item_impl| module_state.get_fn_state(Some(item_impl), name))
+        };
+
+        // Check the SRC-3 `mint` and `burn` functions for total supply updates and events
+        if let Some(item_impl) = src3_impl {
+            let total_supply_names = get_fn_state(src20_impl, "total_supply")
+                .map(|fn_state| fn_state.storage_reads.clone())
+                .unwrap_or_default();
+
+            for fn_name in ["mint", "burn"] {
+                let Some(item_fn) = find_impl_fn(item_impl, fn_name) else { continue };
+                let Some(fn_state) = module_state.get_fn_state(Some(item_impl), fn_name) else { continue };
+
+                if !total_supply_names.is_empty() && !total_supply_names.iter().any(|name| fn_state.storage_writes.contains(name)) {
+                    project.report.borrow_mut().add_entry(
+                        context.path,
+                        project.span_to_line(context.path, &item_fn.fn_signature.span())?,
+                        Severity::High,
+                        format!(
+                            "The `Contract::{fn_name}` function does not update the `{}` storage field reported by the `Contract::total_supply` function. Consider updating the total supply.",
+                            total_supply_names.join("`, `"),
+                        ),
+                    );
+                }
+
+                if !fn_state.logged_events.iter().any(|x| x == "TotalSupplyEvent") {
+                    project.report.borrow_mut().add_entry(
+                        context.path,
+                        project.span_to_line(context.path, &item_fn.fn_signature.span())?,
+                        Severity::Low,
+                        format!(
+                            "The `Contract::{fn_name}` function does not log a `TotalSupplyEvent`. Consider logging the standard event when the total supply changes.",
+                        ),
+                    );
+                }
+            }
+        }
+
+        // Check contract functions which update SRC-20 metadata for events
+        if src20_impl.is_some() {
+            for &(metadata_fn_name, event_name) in SRC20_METADATA_EVENTS {
+                let metadata_names = get_fn_state(src20_impl, metadata_fn_name)
+                    .map(|fn_state| fn_state.storage_reads.clone())
+                    .unwrap_or_default();
+
+                for item in items() {
+                    let ItemKind::Impl(item_impl) = item else { continue };
+
+                    if item_impl.ty.span().as_str() != "Contract" {
+                        continue;
+                    }
+
+                    for item in item_impl.contents.inner.iter() {
+                        let ItemImplItem::Fn(item_fn) = &item.value else { continue };
+                        let Some(fn_state) = module_state.get_fn_state(Some(item_impl), item_fn.fn_signature.name.as_str()) else { continue };
+
+                        let Some(metadata_name) = metadata_names.iter().find(|name| fn_state.storage_writes.contains(*name)) else { continue };
+
+                        if fn_state.logged_events.iter().any(|x| x == event_name) {
+                            continue;
+                        }
+
+                        project.report.borrow_mut().add_entry(
+                            context.path,
+                            project.span_to_line(context.path, &item_fn.fn_signature.span())?,
+                            Severity::Low,
+                            format!(
+                                "The `Contract::{}` function writes to the `{metadata_name}` storage field without logging a `{event_name}`. Consider logging the standard event when the metadata changes.",
+                                item_fn.fn_signature.name.as_str(),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_src20_conformance() {
+        crate::tests::test_detector("src20_conformance", 7);
+    }
+}
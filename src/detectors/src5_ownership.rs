@@ -0,0 +1,413 @@
+use crate::{
+    error::Error,
+    project::{PackageStates, Project},
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext, StatementLetContext, UseContext},
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    path::PathBuf,
+    rc::Rc,
+};
+use sway_ast::{Expr, IfCondition, ItemKind};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct Src5OwnershipVisitor {
+    package_states: PackageStates<PackageState>,
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct PackageState {
+    is_ownership_initialized: bool,
+    ownership_check: Option<(PathBuf, Span, String)>,
+}
+
+/// The module paths the ownership library can be imported from
+const OWNERSHIP_MODULE_PATHS: &[&str] = &["ownership", "sway_libs::ownership"];
+
+/// The functions of the ownership library which are checked
+const OWNERSHIP_FN_NAMES: &[&str] = &["only_owner", "initialize_ownership", "transfer_ownership", "renounce_ownership"];
+
+struct ModuleState {
+    msg_sender_names: Vec<String>,
+    state_names: Vec<String>,
+    ownership_fn_names: HashMap<String, &'static str>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            // Since `std::auth::msg_sender` is part of the prelude, include it here
+            msg_sender_names: vec!["msg_sender".into()],
+            state_names: vec!["src5::State".into()],
+            ownership_fn_names: OWNERSHIP_MODULE_PATHS.iter()
+                .flat_map(|module_path| OWNERSHIP_FN_NAMES.iter().map(move |fn_name| (format!("{module_path}::{fn_name}"), *fn_name)))
+                .collect(),
+            fn_states: Default::default(),
+        }
+    }
+}
+
+impl ModuleState {
+    /// Gets the name of the ownership library function called by `expr`, i.e: `only_owner` for `ownership::only_owner()`
+    fn get_ownership_fn_name(&self, expr: &Expr) -> Option<&'static str> {
+        let Expr::FuncApp { func, .. } = expr else { return None };
+        let Expr::Path(_) = func.as_ref() else { return None };
+
+        self.ownership_fn_names.get(func.span().as_str()).copied()
+    }
+
+    /// Gets the name of the `src5::State` variant constructed by `expr`, i.e: `Initialized` for `State::Initialized(owner)`
+    fn get_state_variant_name(&self, expr: &Expr) -> Option<String> {
+        let path = match expr {
+            Expr::FuncApp { func, .. } => func.as_ref(),
+            Expr::Path(_) => expr,
+            _ => return None,
+        };
+
+        let Expr::Path(_) = path else { return None };
+        let path = path.span();
+        let (prefix, variant_name) = path.as_str().rsplit_once("::")?;
+
+        if !self.state_names.iter().any(|name| name == prefix) {
+            return None;
+        }
+
+        Some(variant_name.to_string())
+    }
+
+    /// Checks if `expr` contains a comparison of a storage read against a `src5::State` variant, i.e: `storage.owner.read() == State::Uninitialized`
+    fn expr_contains_state_check(&self, expr: &Expr) -> bool {
+        let mut result = false;
+
+        utils::map_expr(expr, &mut |expr| match expr {
+            Expr::Equal { lhs, rhs, .. } | Expr::NotEqual { lhs, rhs, .. } => {
+                if (is_storage_read(lhs) && self.get_state_variant_name(rhs).is_some())
+                    || (is_storage_read(rhs) && self.get_state_variant_name(lhs).is_some())
+                {
+                    result = true;
+                }
+            }
+
+            _ => {}
+        });
+
+        result
+    }
+
+    /// Checks if `expr` contains a call to `msg_sender()` or a variable bound to it.
+    fn expr_contains_msg_sender(&self, fn_state: &FnState, expr: &Expr) -> bool {
+        let mut result = false;
+
+        utils::map_expr(expr, &mut |expr| match expr {
+            Expr::FuncApp { func, .. } => {
+                let func = func.span();
+
+                if func.as_str() == "std::auth::msg_sender" || self.msg_sender_names.iter().any(|name| func.as_str() == name) {
+                    result = true;
+                }
+            }
+
+            Expr::Path(_) => {
+                if fn_state.msg_sender_var_names.iter().any(|name| expr.span().as_str() == name) {
+                    result = true;
+                }
+            }
+
+            _ => {}
+        });
+
+        result
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    msg_sender_var_names: Vec<String>,
+    has_access_check: bool,
+    has_state_check: bool,
+    ownership_changes: Vec<OwnershipChange>,
+}
+
+struct OwnershipChange {
+    kind: OwnershipChangeKind,
+    span: Span,
+}
+
+#[derive(Clone, Copy)]
+enum OwnershipChangeKind {
+    /// A call to `initialize_ownership(...)`, which reverts if ownership is already initialized
+    LibraryInitialization,
+    /// A storage write of `State::Initialized(...)`
+    StateInitialization,
+    /// A call to `transfer_ownership(...)` or `renounce_ownership()`
+    LibraryTransfer,
+    /// A storage write of `State::Revoked`
+    StateRevocation,
+}
+
+/// Checks if `expr` is a storage chain, i.e: `storage.owner`
+fn is_storage_expr(expr: &Expr) -> bool {
+    utils::fold_expr_idents(expr).first().is_some_and(|ident| ident.as_str() == "storage")
+}
+
+/// Checks if `expr` reads a value from storage, i.e: `storage.owner.read()`
+fn is_storage_read(expr: &Expr) -> bool {
+    let Expr::MethodCall { target, path_seg, .. } = expr else { return false };
+
+    path_seg.name.as_str() == "read" && is_storage_expr(target)
+}
+
+impl AstVisitor for Src5OwnershipVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        // Create the package state
+        self.package_states.visit_module(project, context.path);
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check the package once all of its modules have been visited
+        let Some(package_state) = self.package_states.leave_module(context.path) else { return Ok(()) };
+
+        // Check for ownership which is checked but never initialized
+        if package_state.is_ownership_initialized {
+            return Ok(());
+        }
+
+        let Some((path, span, location)) = package_state.ownership_check.as_ref() else { return Ok(()) };
+
+        project.report.borrow_mut().add_entry(
+            path,
+            project.span_to_line(path, span)?,
+            Severity::High,
+            format!(
+                "{} restricts access to the SRC-5 owner, but ownership is never initialized: `{}`. Consider calling `initialize_ownership` in a protected initializer.",
+                location,
+                span.as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::auth::msg_sender`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::auth::msg_sender") {
+            module_state.msg_sender_names.push(name);
+        }
+
+        // Check the use tree for `src5::State`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "src5::State") {
+            module_state.state_names.push(name);
+        }
+
+        // Check the use tree for the functions of the ownership library
+        for module_path in OWNERSHIP_MODULE_PATHS {
+            for fn_name in OWNERSHIP_FN_NAMES {
+                if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, format!("{module_path}::{fn_name}").as_str()) {
+                    module_state.ownership_fn_names.insert(name, *fn_name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature).or_default();
+
+        Ok(())
+    }
+
+    fn leave_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check contract ABI functions, since internal functions can be protected by their callers
+        let Some(item_impl) = context.item_impl.as_ref() else { return Ok(()) };
+
+        if item_impl.ty.span().as_str() != "Contract" {
+            return Ok(());
+        }
+
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        if fn_state.has_access_check {
+            return Ok(());
+        }
+
+        for ownership_change in fn_state.ownership_changes.iter() {
+            let description = match ownership_change.kind {
+                OwnershipChangeKind::StateInitialization if !fn_state.has_state_check => {
+                    "initializes ownership without checking that it is uninitialized, which allows ownership to be re-initialized by anyone"
+                }
+
+                OwnershipChangeKind::LibraryInitialization | OwnershipChangeKind::StateInitialization => {
+                    "initializes ownership without access restriction, which allows anyone to claim ownership before the intended owner"
+                }
+
+                OwnershipChangeKind::LibraryTransfer | OwnershipChangeKind::StateRevocation => {
+                    "changes ownership without access restriction"
+                }
+            };
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &ownership_change.span)?,
+                Severity::High,
+                format!(
+                    "{} {}: `{}`. Consider calling `only_owner()` or checking against `msg_sender()` in order to limit access.",
+                    utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                    description,
+                    ownership_change.span.as_str(),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Check if the variable stores `msg_sender()`
+        let idents = utils::fold_pattern_idents(&context.statement_let.pattern);
+
+        if idents.len() != 1 || !module_state.expr_contains_msg_sender(fn_state, &context.statement_let.expr) {
+            return Ok(());
+        }
+
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+        fn_state.msg_sender_var_names.push(idents[0].as_str().to_string());
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the package state
+        let package_state = self.package_states.get_mut(context.path);
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check for ownership initialized in a storage field initializer, i.e: `owner: State = State::Initialized(...)`
+        if matches!(context.item, ItemKind::Storage(_)) {
+            if module_state.get_state_variant_name(context.expr).is_some_and(|name| name == "Initialized") {
+                package_state.is_ownership_initialized = true;
+            }
+
+            return Ok(());
+        }
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let Some(fn_state) = module_state.fn_states.get(&fn_signature) else { return Ok(()) };
+
+        // Check for a `require` or `if`-`revert` which checks against `msg_sender()` or the ownership state
+        let condition = if let Some(require_args) = utils::get_require_args(context.expr) {
+            require_args.first().cloned()
+        } else if let Some(IfCondition::Expr(expr)) = utils::get_if_revert_condition(context.expr) {
+            Some(expr.as_ref())
+        } else {
+            None
+        };
+
+        if let Some(condition) = condition {
+            let has_access_check = module_state.expr_contains_msg_sender(fn_state, condition);
+            let has_state_check = module_state.expr_contains_state_check(condition);
+
+            let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+            fn_state.has_access_check |= has_access_check;
+            fn_state.has_state_check |= has_state_check;
+
+            return Ok(());
+        }
+
+        let kind = match context.expr {
+            // Check for calls to the ownership library, i.e: `only_owner()` or `initialize_ownership(owner)`
+            Expr::FuncApp { .. } => match module_state.get_ownership_fn_name(context.expr) {
+                Some("only_owner") => {
+                    let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+                    fn_state.has_access_check = true;
+
+                    if package_state.ownership_check.is_none() {
+                        package_state.ownership_check = Some((
+                            context.path.into(),
+                            context.expr.span(),
+                            utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                        ));
+                    }
+
+                    return Ok(());
+                }
+
+                Some("initialize_ownership") => OwnershipChangeKind::LibraryInitialization,
+                Some("transfer_ownership" | "renounce_ownership") => OwnershipChangeKind::LibraryTransfer,
+                _ => return Ok(()),
+            },
+
+            // Check for storage writes of the ownership state, i.e: `storage.owner.write(State::Initialized(owner))`
+            Expr::MethodCall { target, path_seg, args, .. } if path_seg.name.as_str() == "write" && is_storage_expr(target) => {
+                let Some(value) = utils::fold_punctuated(&args.inner).first().cloned() else { return Ok(()) };
+
+                match module_state.get_state_variant_name(value).as_deref() {
+                    Some("Initialized") => OwnershipChangeKind::StateInitialization,
+                    Some("Revoked") => OwnershipChangeKind::StateRevocation,
+                    _ => return Ok(()),
+                }
+            }
+
+            _ => return Ok(()),
+        };
+
+        if matches!(kind, OwnershipChangeKind::LibraryInitialization | OwnershipChangeKind::StateInitialization) {
+            package_state.is_ownership_initialized = true;
+        }
+
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.ownership_changes.push(OwnershipChange {
+            kind,
+            span: context.expr.span(),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_src5_ownership() {
+        crate::tests::test_detector("src5_ownership", 7);
+    }
+}
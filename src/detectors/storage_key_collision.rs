@@ -0,0 +1,160 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ModuleContext, StorageFieldContext},
+};
+use sha2::{Digest, Sha256};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, ItemKind, Literal};
+use sway_types::Spanned;
+
+/// The storage slots reserved for the proxy target and owner by the SRC-14 standard.
+const SRC14_SLOTS: &[(&str, &str)] = &[
+    ("target", "7bb458adc1d118713319a5baa00a2d049dd64d2916477d2688d76970c898cd55"),
+    ("owner", "bb79927b15d9259ea316f2ecb2297d6cc8851888a98278c0a2e03e1a091ea754"),
+];
+
+#[derive(Default)]
+pub struct StorageKeyCollisionVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    const_keys: HashMap<String, String>,
+    implements_src14: bool,
+    storage_keys: Vec<StorageKeyState>,
+}
+
+struct StorageKeyState {
+    name: String,
+    key: String,
+    is_explicit: bool,
+}
+
+/// Gets the storage key of a field without an explicit key, i.e: `sha256("storage.x")` or `sha256("storage::my_namespace.x")`.
+fn get_default_storage_key(namespace: Option<&str>, name: &str) -> String {
+    let key = match namespace {
+        Some(namespace) => format!("storage::{namespace}.{name}"),
+        None => format!("storage.{name}"),
+    };
+
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
+
+/// Attempts to get the storage key of an integer literal as a 64 digit hex string.
+fn get_literal_storage_key(expr: &Expr) -> Option<String> {
+    let Expr::Literal(Literal::Int(value)) = expr else { return None };
+    Some(format!("{:064x}", value.parsed))
+}
+
+impl AstVisitor for StorageKeyCollisionVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        let module_state = self.module_states.entry(context.path.into()).or_default();
+
+        for item in context.module.items.iter() {
+            match &item.value {
+                // Store the values of constants ahead of time, i.e: `const KEY: b256 = 0x...;`
+                ItemKind::Const(item_const) => {
+                    if let Some(key) = item_const.expr_opt.as_ref().and_then(get_literal_storage_key) {
+                        module_state.const_keys.insert(item_const.name.as_str().into(), key);
+                    }
+                }
+
+                // Check if the module implements the SRC-14 proxy standard, i.e: `impl SRC14 for Contract`
+                ItemKind::Impl(item_impl) => {
+                    if let Some((path_type, _)) = item_impl.trait_opt.as_ref() {
+                        if path_type.span().as_str().rsplit("::").next() == Some("SRC14") {
+                            module_state.implements_src14 = true;
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_storage_field(&mut self, context: &StorageFieldContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        let name = context.field.name.as_str().to_string();
+
+        // Get the namespace of the storage block, i.e: `#[namespace(my_namespace)]`
+        let namespace = utils::get_storage_namespace(context.storage_attributes);
+
+        // Get the storage key of the field, i.e: `x in 0x...: u64` or `sha256("storage.x")`
+        let (key, is_explicit) = match context.field.key_expr.as_ref() {
+            Some(key_expr) => {
+                let key = match key_expr {
+                    Expr::Path(_) => module_state.const_keys.get(key_expr.span().as_str()).cloned(),
+                    _ => get_literal_storage_key(key_expr),
+                };
+
+                let Some(key) = key else { return Ok(()) };
+                (key, true)
+            }
+
+            None => (get_default_storage_key(namespace.as_deref(), name.as_str()), false),
+        };
+
+        // Check if the storage key collides with the storage key of a previous field
+        if let Some(other) = module_state.storage_keys.iter().find(|other| other.key == key && (is_explicit || other.is_explicit)) {
+            let description = match (is_explicit, other.is_explicit) {
+                (true, true) => format!("has the same explicit storage key as the `{}` storage field", other.name),
+                (true, false) => format!("has an explicit storage key equal to the default storage key of the `{}` storage field", other.name),
+                _ => format!("has a default storage key equal to the explicit storage key of the `{}` storage field", other.name),
+            };
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &context.field.name.span())?,
+                Severity::High,
+                format!(
+                    "The `{}` storage field {}. Consider using a unique storage key.",
+                    name,
+                    description,
+                ),
+            );
+        }
+
+        // Check if the storage key overlaps an SRC-14 proxy slot outside of a proxy contract
+        if is_explicit && !module_state.implements_src14 {
+            if let Some((slot_name, _)) = SRC14_SLOTS.iter().find(|(_, slot_key)| *slot_key == key) {
+                project.report.borrow_mut().add_entry(
+                    context.path,
+                    project.span_to_line(context.path, &context.field.name.span())?,
+                    Severity::High,
+                    format!(
+                        "The `{}` storage field has an explicit storage key which overlaps the SRC-14 proxy {} slot. Consider using a storage key which does not collide with the proxy's storage.",
+                        name,
+                        slot_name,
+                    ),
+                );
+            }
+        }
+
+        module_state.storage_keys.push(StorageKeyState {
+            name,
+            key,
+            is_explicit,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_storage_key_collision() {
+        crate::tests::test_detector("storage_key_collision", 4);
+    }
+}
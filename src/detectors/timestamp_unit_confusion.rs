@@ -0,0 +1,284 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ConstContext, ExprContext, FnContext, ModuleContext, StatementLetContext, UseContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, Literal};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct TimestampUnitConfusionVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    timestamp_names: Vec<String>,
+    timestamp_of_block_names: Vec<String>,
+    height_names: Vec<String>,
+    unix_timestamp_constant_names: Vec<String>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+#[derive(Default)]
+struct FnState {
+    timestamp_variable_names: Vec<String>,
+    height_variable_names: Vec<String>,
+}
+
+/// Checks if `expr` is an integer literal that looks like a Unix timestamp in seconds, i.e: `1700000000`
+fn is_unix_timestamp_literal(expr: &Expr) -> bool {
+    let Expr::Literal(Literal::Int(i)) = expr else { return false };
+
+    if i.span.as_str().starts_with("0x") {
+        return false;
+    }
+
+    i.parsed >= 1_000_000_000u64.into() && i.parsed < 10_000_000_000u64.into()
+}
+
+/// Checks if `expr` is a single identifier contained in `names`, i.e: `deadline`
+fn is_named_value(expr: &Expr, names: &[String]) -> bool {
+    let Expr::Path(path) = expr else { return false };
+
+    if path.root_opt.is_some() || !path.suffix.is_empty() {
+        return false;
+    }
+
+    names.iter().any(|name| name == path.prefix.name.as_str())
+}
+
+impl ModuleState {
+    /// Checks if `expr` evaluates to a TAI64 timestamp, i.e: `timestamp()` or `timestamp() + 3600`
+    fn is_timestamp_value(&self, fn_state: &FnState, expr: &Expr) -> bool {
+        match expr {
+            Expr::FuncApp { .. } => {
+                utils::is_fn_call(expr, "std::block::timestamp", self.timestamp_names.as_slice())
+                    || utils::is_fn_call(expr, "std::block::timestamp_of_block", self.timestamp_of_block_names.as_slice())
+            }
+
+            Expr::Path(_) => is_named_value(expr, fn_state.timestamp_variable_names.as_slice()),
+
+            Expr::Parens(parens) => self.is_timestamp_value(fn_state, parens.inner.as_ref()),
+
+            Expr::Add { lhs, rhs, .. } => {
+                self.is_timestamp_value(fn_state, lhs.as_ref()) || self.is_timestamp_value(fn_state, rhs.as_ref())
+            }
+
+            // The difference between two timestamps is a duration, not a timestamp
+            Expr::Sub { lhs, rhs, .. } => {
+                self.is_timestamp_value(fn_state, lhs.as_ref()) && !self.is_timestamp_value(fn_state, rhs.as_ref())
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Checks if `expr` evaluates to a block height, i.e: `height()` or `height() + 10`
+    fn is_height_value(&self, fn_state: &FnState, expr: &Expr) -> bool {
+        match expr {
+            Expr::FuncApp { .. } => utils::is_fn_call(expr, "std::block::height", self.height_names.as_slice()),
+
+            Expr::Path(_) => is_named_value(expr, fn_state.height_variable_names.as_slice()),
+
+            Expr::Parens(parens) => self.is_height_value(fn_state, parens.inner.as_ref()),
+
+            Expr::Add { lhs, rhs, .. } => {
+                self.is_height_value(fn_state, lhs.as_ref()) || self.is_height_value(fn_state, rhs.as_ref())
+            }
+
+            Expr::Sub { lhs, rhs, .. } => {
+                self.is_height_value(fn_state, lhs.as_ref()) && !self.is_height_value(fn_state, rhs.as_ref())
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Checks if `expr` is a literal or constant that looks like a Unix timestamp, i.e: `1700000000` or `DEADLINE`
+    fn is_unix_timestamp_value(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Literal(_) => is_unix_timestamp_literal(expr),
+            Expr::Path(_) => is_named_value(expr, self.unix_timestamp_constant_names.as_slice()),
+            Expr::Parens(parens) => self.is_unix_timestamp_value(parens.inner.as_ref()),
+            _ => false,
+        }
+    }
+}
+
+impl AstVisitor for TimestampUnitConfusionVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::block::timestamp`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::block::timestamp") {
+            module_state.timestamp_names.push(name);
+        }
+
+        // Check the use tree for `std::block::timestamp_of_block`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::block::timestamp_of_block") {
+            module_state.timestamp_of_block_names.push(name);
+        }
+
+        // Check the use tree for `std::block::height`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::block::height") {
+            module_state.height_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_const(&mut self, context: &ConstContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check module-level constants
+        if context.item_impl.is_some() || context.item_fn.is_some() {
+            return Ok(());
+        }
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Store the names of constants which look like Unix timestamps, i.e: `const DEADLINE: u64 = 1700000000;`
+        if context.item_const.expr_opt.as_ref().map(is_unix_timestamp_literal).unwrap_or(false) {
+            module_state.unix_timestamp_constant_names.push(context.item_const.name.as_str().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature).or_default();
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check single variable bindings, i.e: `let deadline = timestamp() + 3600;`
+        let idents = utils::fold_pattern_idents(&context.statement_let.pattern);
+
+        if idents.len() != 1 {
+            return Ok(());
+        }
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Check if the variable is bound to a timestamp or a block height
+        let is_timestamp = module_state.is_timestamp_value(fn_state, &context.statement_let.expr);
+        let is_height = module_state.is_height_value(fn_state, &context.statement_let.expr);
+
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        if is_timestamp {
+            fn_state.timestamp_variable_names.push(idents[0].as_str().to_string());
+        } else if is_height {
+            fn_state.height_variable_names.push(idents[0].as_str().to_string());
+        }
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let Some(fn_state) = module_state.fn_states.get(&fn_signature) else { return Ok(()) };
+
+        let (lhs, rhs, is_comparison) = match context.expr {
+            Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. } => (lhs.as_ref(), rhs.as_ref(), true),
+
+            Expr::Add { lhs, rhs, .. }
+            | Expr::Sub { lhs, rhs, .. }
+            | Expr::Mul { lhs, rhs, .. }
+            | Expr::Div { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. } => (lhs.as_ref(), rhs.as_ref(), false),
+
+            _ => return Ok(()),
+        };
+
+        // Check for timestamps compared against Unix timestamps, i.e: `timestamp() > 1700000000`
+        if is_comparison {
+            let unix_timestamp = if module_state.is_timestamp_value(fn_state, lhs) && module_state.is_unix_timestamp_value(rhs) {
+                Some(rhs)
+            } else if module_state.is_unix_timestamp_value(lhs) && module_state.is_timestamp_value(fn_state, rhs) {
+                Some(lhs)
+            } else {
+                None
+            };
+
+            if let Some(unix_timestamp) = unix_timestamp {
+                project.report.borrow_mut().add_entry(
+                    context.path,
+                    project.span_to_line(context.path, &context.expr.span())?,
+                    Severity::Medium,
+                    format!(
+                        "{} compares a TAI64 timestamp against `{}`, which looks like a Unix timestamp: `{}`. Consider converting between TAI64 and Unix timestamps.",
+                        utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                        unix_timestamp.span().as_str(),
+                        context.expr.span().as_str(),
+                    ),
+                );
+
+                return Ok(());
+            }
+        }
+
+        // Check for timestamps mixed with block heights, i.e: `timestamp() + height()`
+        let is_mixed = (module_state.is_timestamp_value(fn_state, lhs) && module_state.is_height_value(fn_state, rhs))
+            || (module_state.is_height_value(fn_state, lhs) && module_state.is_timestamp_value(fn_state, rhs));
+
+        if is_mixed {
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &context.expr.span())?,
+                Severity::Medium,
+                format!(
+                    "{} mixes a TAI64 timestamp with a block height: `{}`. Consider using consistent units of time.",
+                    utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                    context.expr.span().as_str(),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_timestamp_unit_confusion() {
+        crate::tests::test_detector("timestamp_unit_confusion", 5);
+    }
+}
@@ -0,0 +1,391 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{
+        AstVisitor, BlockContext, ExprContext, FnContext, ForExprContext, ModuleContext,
+        StatementLetContext, UseContext, WhileExprContext,
+    },
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, IfCondition, Pattern};
+use sway_types::{Span, Spanned};
+
+//
+// If any caller is able to grow a storage collection, loops bounded by its length
+// will eventually require more gas than a single transaction can provide.
+//
+
+#[derive(Default)]
+pub struct UnboundedStorageIterationVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+struct ModuleState {
+    msg_sender_names: Vec<String>,
+    collection_fields: Vec<String>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            // Since `std::auth::msg_sender` is part of the prelude, include it here
+            msg_sender_names: vec!["msg_sender".into()],
+            collection_fields: vec![],
+            fn_states: Default::default(),
+        }
+    }
+}
+
+impl ModuleState {
+    fn expr_is_access_check(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::FuncApp { func, .. } => {
+                let name = func.span().as_str().to_string();
+                name == "std::auth::msg_sender" || name == "only_owner" || self.msg_sender_names.contains(&name)
+            }
+
+            Expr::MethodCall { target, .. } => self.expr_is_access_check(target.as_ref()),
+
+            Expr::Equal { lhs, rhs, .. } |
+            Expr::NotEqual { lhs, rhs, .. } |
+            Expr::LogicalAnd { lhs, rhs, .. } |
+            Expr::LogicalOr { lhs, rhs, .. } => {
+                self.expr_is_access_check(lhs.as_ref()) || self.expr_is_access_check(rhs.as_ref())
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Attempts to get the name of the storage collection field whose length is read by `expr`, i.e: `storage.x.len()`
+    fn expr_to_len_field(&self, expr: &Expr) -> Option<String> {
+        let idents = utils::fold_expr_idents(expr);
+
+        if idents.len() != 3 || idents[0].as_str() != "storage" || idents[2].as_str() != "len" {
+            return None;
+        }
+
+        let field = idents[1].as_str().to_string();
+        self.collection_fields.contains(&field).then_some(field)
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    name: String,
+    is_abi_fn: bool,
+    has_access_check: bool,
+    growth_fields: Vec<String>,
+    loop_states: Vec<LoopState>,
+    block_states: HashMap<Span, BlockState>,
+}
+
+impl FnState {
+    fn find_len_var_field(&self, name: &str, blocks: &[Span]) -> Option<String> {
+        for block_span in blocks.iter().rev() {
+            let block_state = self.block_states.get(block_span).unwrap();
+
+            if let Some((_, field)) = block_state.len_vars.iter().rev().find(|(var_name, _)| var_name == name) {
+                return Some(field.clone());
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Default)]
+struct BlockState {
+    len_vars: Vec<(String, String)>, // (variable name, storage field name)
+}
+
+struct LoopState {
+    field: String,
+    span: Span,
+    location: String,
+}
+
+fn collect_len_fields(module_state: &ModuleState, fn_state: &FnState, expr: &Expr, blocks: &[Span], fields: &mut Vec<String>) {
+    match expr {
+        Expr::Parens(parens) => collect_len_fields(module_state, fn_state, &parens.inner, blocks, fields),
+
+        Expr::Mul { lhs, rhs, .. } |
+        Expr::Div { lhs, rhs, .. } |
+        Expr::Add { lhs, rhs, .. } |
+        Expr::Sub { lhs, rhs, .. } |
+        Expr::Equal { lhs, rhs, .. } |
+        Expr::NotEqual { lhs, rhs, .. } |
+        Expr::LessThan { lhs, rhs, .. } |
+        Expr::GreaterThan { lhs, rhs, .. } |
+        Expr::LessThanEq { lhs, rhs, .. } |
+        Expr::GreaterThanEq { lhs, rhs, .. } |
+        Expr::LogicalAnd { lhs, rhs, .. } |
+        Expr::LogicalOr { lhs, rhs, .. } => {
+            collect_len_fields(module_state, fn_state, lhs.as_ref(), blocks, fields);
+            collect_len_fields(module_state, fn_state, rhs.as_ref(), blocks, fields);
+        }
+
+        // Check for variables bound to the length of a storage collection, i.e: `let len = storage.x.len();`
+        Expr::Path(_) => {
+            if let Some(field) = fn_state.find_len_var_field(expr.span().as_str(), blocks) {
+                fields.push(field);
+            }
+        }
+
+        _ => {
+            if let Some(field) = module_state.expr_to_len_field(expr) {
+                fields.push(field);
+            }
+        }
+    }
+}
+
+impl AstVisitor for UnboundedStorageIterationVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get or create the module state
+        let module_state = self.module_states.entry(context.path.into()).or_default();
+
+        // Store the names of storage collection fields ahead of time
+        for storage_field in utils::collect_storage_fields(context.module) {
+            let ty = storage_field.ty.span();
+
+            if ty.as_str().starts_with("StorageVec") || ty.as_str().starts_with("StorageMap") {
+                module_state.collection_fields.push(storage_field.name.as_str().into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::auth::msg_sender`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::auth::msg_sender") {
+            module_state.msg_sender_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.entry(fn_signature).or_default();
+
+        fn_state.name = match context.item_impl.as_ref() {
+            Some(item_impl) => format!("{}::{}", item_impl.ty.span().as_str(), context.item_fn.fn_signature.name.as_str()),
+            None => context.item_fn.fn_signature.name.as_str().to_string(),
+        };
+
+        // Only functions implementing an abi for `Contract` are publicly accessible
+        if let Some(item_impl) = context.item_impl {
+            fn_state.is_abi_fn = item_impl.trait_opt.is_some() && item_impl.ty.span().as_str() == "Contract";
+        }
+
+        Ok(())
+    }
+
+    fn visit_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Create the block state
+        let block_span = context.block.span();
+
+        fn_state.block_states.entry(block_span).or_default();
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check single variable patterns
+        let Pattern::AmbiguousSingleIdent(ident) = &context.statement_let.pattern else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Only check variables bound to the length of a storage collection, i.e: `let len = storage.x.len();`
+        let Some(field) = module_state.expr_to_len_field(&context.statement_let.expr) else { return Ok(()) };
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Get the block state
+        let block_span = context.blocks.last().unwrap();
+        let block_state = fn_state.block_states.get_mut(block_span).unwrap();
+
+        block_state.len_vars.push((ident.as_str().to_string(), field));
+
+        Ok(())
+    }
+
+    fn visit_while_expr(&mut self, context: &WhileExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Check if the loop condition depends on the length of a storage collection
+        let mut fields = vec![];
+        collect_len_fields(module_state, fn_state, context.condition, context.blocks.as_slice(), &mut fields);
+
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        for field in fields {
+            fn_state.loop_states.push(LoopState {
+                field,
+                span: context.expr.span(),
+                location: utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn visit_for_expr(&mut self, context: &ForExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check iteration over storage collections, i.e: `for x in storage.x.iter() { ... }`
+        let idents = utils::fold_expr_idents(context.iterator);
+
+        if idents.len() != 3 || idents[0].as_str() != "storage" || idents[2].as_str() != "iter" {
+            return Ok(());
+        }
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        let field = idents[1].as_str().to_string();
+
+        if !module_state.collection_fields.contains(&field) {
+            return Ok(());
+        }
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.loop_states.push(LoopState {
+            field,
+            span: context.expr.span(),
+            location: utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+        });
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check for access restriction via `require` or `if/revert`
+        let has_access_check = if let Some(args) = utils::get_require_args(context.expr) {
+            args.iter().any(|arg| module_state.expr_is_access_check(arg))
+        } else if let Some(IfCondition::Expr(condition)) = utils::get_if_revert_condition(context.expr) {
+            module_state.expr_is_access_check(condition.as_ref())
+        } else if let Expr::FuncApp { func, .. } = context.expr {
+            func.span().as_str() == "only_owner"
+        } else {
+            false
+        };
+
+        // Check for growth of a storage collection, i.e: `storage.x.push(y);`
+        let mut growth_field = None;
+
+        if let Expr::MethodCall { .. } = context.expr {
+            let idents = utils::fold_expr_idents(context.expr);
+
+            if idents.len() == 3 && idents[0].as_str() == "storage" && matches!(idents[2].as_str(), "push" | "insert") {
+                let field = idents[1].as_str().to_string();
+
+                if module_state.collection_fields.contains(&field) {
+                    growth_field = Some(field);
+                }
+            }
+        }
+
+        if !has_access_check && growth_field.is_none() {
+            return Ok(());
+        }
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        if has_access_check {
+            fn_state.has_access_check = true;
+        }
+
+        if let Some(field) = growth_field {
+            if !fn_state.growth_fields.contains(&field) {
+                fn_state.growth_fields.push(field);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Collect the loop states in source order
+        let mut loop_states = module_state.fn_states.values()
+            .flat_map(|x| x.loop_states.iter())
+            .collect::<Vec<_>>();
+
+        loop_states.sort_by_key(|x| x.span.start());
+
+        for loop_state in loop_states {
+            // Find all publicly-accessible functions which can grow the collection without access restriction
+            let mut fn_names = module_state.fn_states.values()
+                .filter(|x| x.is_abi_fn && !x.has_access_check && x.growth_fields.contains(&loop_state.field))
+                .map(|x| format!("`{}`", x.name))
+                .collect::<Vec<_>>();
+
+            if fn_names.is_empty() {
+                continue;
+            }
+
+            fn_names.sort();
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &loop_state.span)?,
+                Severity::Medium,
+                format!(
+                    "{} contains a loop bounded by the length of `storage.{}`, which can be grown without access restriction by the {} {}. Consider limiting the number of iterations.",
+                    loop_state.location,
+                    loop_state.field,
+                    fn_names.join(", "),
+                    if fn_names.len() == 1 { "function" } else { "functions" },
+                ),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_unbounded_storage_iteration() {
+        crate::tests::test_detector("unbounded_storage_iteration", 2);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils::{self, ValueSource, VarSources},
+    visitor::{
+        AstVisitor, BlockContext, ExprContext, FnContext, IfExprContext, ModuleContext,
+        StatementLetContext, UseContext,
+    },
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, IfCondition, Literal, Pattern};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct UncheckedDivisorVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+struct ModuleState {
+    msg_amount_names: Vec<String>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            msg_amount_names: vec!["std::context::msg_amount".into()],
+            fn_states: Default::default(),
+        }
+    }
+}
+
+impl ModuleState {
+    fn expr_is_msg_amount_call(&self, expr: &Expr) -> bool {
+        let Expr::FuncApp { func, .. } = expr else { return false };
+
+        self.msg_amount_names.iter().any(|name| func.span().as_str() == name)
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    block_states: HashMap<Span, BlockState>,
+    var_sources: VarSources,
+}
+
+impl FnState {
+    fn is_checked(&self, name: &str, blocks: &[Span]) -> bool {
+        blocks.iter().any(|block_span| {
+            let block_state = self.block_states.get(block_span).unwrap();
+            block_state.checked_names.iter().any(|checked_name| checked_name == name)
+        })
+    }
+}
+
+#[derive(Default)]
+struct BlockState {
+    checked_names: Vec<String>,
+}
+
+fn classify_expr(module_state: &ModuleState, fn_state: &FnState, expr: &Expr, blocks: &[Span], scope: Rc<RefCell<AstScope>>) -> Option<ValueSource> {
+    fn_state.var_sources.classify_expr(expr, blocks, scope, &|expr| {
+        module_state.expr_is_msg_amount_call(expr).then_some(ValueSource::MsgAmount)
+    })
+}
+
+fn is_zero_literal(expr: &Expr) -> bool {
+    let Expr::Literal(Literal::Int(value)) = expr else { return false };
+    value.parsed == 0u8.into()
+}
+
+fn collect_non_zero_checks(expr: &Expr, names: &mut Vec<String>) {
+    match expr {
+        Expr::Parens(parens) => collect_non_zero_checks(&parens.inner, names),
+
+        Expr::LogicalAnd { lhs, rhs, .. } => {
+            collect_non_zero_checks(lhs.as_ref(), names);
+            collect_non_zero_checks(rhs.as_ref(), names);
+        }
+
+        // Check for `x != 0` and `x > 0`
+        Expr::NotEqual { lhs, rhs, .. } | Expr::GreaterThan { lhs, rhs, .. } if is_zero_literal(rhs.as_ref()) => {
+            names.push(lhs.span().as_str().to_string());
+        }
+
+        // Check for `0 != x` and `0 < x`
+        Expr::NotEqual { lhs, rhs, .. } | Expr::LessThan { lhs, rhs, .. } if is_zero_literal(lhs.as_ref()) => {
+            names.push(rhs.span().as_str().to_string());
+        }
+
+        _ => {}
+    }
+}
+
+fn collect_zero_checks(expr: &Expr, names: &mut Vec<String>) {
+    match expr {
+        Expr::Parens(parens) => collect_zero_checks(&parens.inner, names),
+
+        Expr::LogicalOr { lhs, rhs, .. } => {
+            collect_zero_checks(lhs.as_ref(), names);
+            collect_zero_checks(rhs.as_ref(), names);
+        }
+
+        // Check for `x == 0`
+        Expr::Equal { lhs, rhs, .. } if is_zero_literal(rhs.as_ref()) => {
+            names.push(lhs.span().as_str().to_string());
+        }
+
+        // Check for `0 == x`
+        Expr::Equal { lhs, rhs, .. } if is_zero_literal(lhs.as_ref()) => {
+            names.push(rhs.span().as_str().to_string());
+        }
+
+        _ => {}
+    }
+}
+
+impl AstVisitor for UncheckedDivisorVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::context::msg_amount`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::context::msg_amount") {
+            module_state.msg_amount_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature).or_default();
+
+        Ok(())
+    }
+
+    fn visit_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Create the block state
+        let block_span = context.block.span();
+
+        fn_state.block_states.entry(block_span).or_default();
+
+        Ok(())
+    }
+
+    fn visit_if_expr(&mut self, context: &IfExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check expression conditions
+        let IfCondition::Expr(condition) = &context.if_expr.condition else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Create the then block state ahead of time and store any values checked to be non-zero by the condition
+        let block_span = context.if_expr.then_block.span();
+        let block_state = fn_state.block_states.entry(block_span).or_default();
+
+        collect_non_zero_checks(condition.as_ref(), &mut block_state.checked_names);
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check single variable patterns
+        let Pattern::AmbiguousSingleIdent(ident) = &context.statement_let.pattern else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Determine where the variable's value comes from
+        let source = classify_expr(module_state, fn_state, &context.statement_let.expr, context.blocks.as_slice(), scope);
+
+        // Store the variable's source in the current block
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+        let block_span = context.blocks.last().unwrap();
+
+        fn_state.var_sources.add_var(block_span, ident.as_str(), source, false);
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Check for `require(x != 0, ...)` and store the values checked to be non-zero
+        if let Some(args) = utils::get_require_args(context.expr) {
+            let Some(block_span) = context.blocks.last() else { return Ok(()) };
+            let block_state = fn_state.block_states.get_mut(block_span).unwrap();
+
+            if let Some(condition) = args.first() {
+                collect_non_zero_checks(condition, &mut block_state.checked_names);
+            }
+
+            return Ok(());
+        }
+
+        // Check for `if x == 0 { revert(0) }` and store the values checked to be non-zero
+        if let Some(IfCondition::Expr(condition)) = utils::get_if_revert_condition(context.expr) {
+            let Some(block_span) = context.blocks.last() else { return Ok(()) };
+            let block_state = fn_state.block_states.get_mut(block_span).unwrap();
+
+            collect_zero_checks(condition.as_ref(), &mut block_state.checked_names);
+
+            return Ok(());
+        }
+
+        // Only check division and modulo expressions
+        let (Expr::Div { rhs, .. } | Expr::Modulo { rhs, .. }) = context.expr else { return Ok(()) };
+
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Check if the divisor has been checked to be non-zero
+        if fn_state.is_checked(rhs.span().as_str(), context.blocks.as_slice()) {
+            return Ok(());
+        }
+
+        // Check if the divisor is derived from a parameter, `msg_amount()` or a storage read
+        let Some(source) = classify_expr(module_state, fn_state, rhs.as_ref(), context.blocks.as_slice(), scope) else { return Ok(()) };
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &context.expr.span())?,
+            Severity::Medium,
+            format!(
+                "{} contains a division by {} which is not checked for a zero value: `{}`. Consider requiring `{}` to be non-zero.",
+                utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                source.description(),
+                context.expr.span().as_str(),
+                rhs.span().as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_unchecked_divisor() {
+        crate::tests::test_detector("unchecked_divisor", 4);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils::{self, ValueSource, VarSources},
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext, StatementLetContext, UseContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, Pattern};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct UncheckedUnwrapVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+struct ModuleState {
+    msg_sender_names: Vec<String>,
+    fn_states: HashMap<Span, FnState>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            // Since `std::auth::msg_sender` is part of the prelude, include it here
+            msg_sender_names: vec!["msg_sender".into()],
+            fn_states: Default::default(),
+        }
+    }
+}
+
+impl ModuleState {
+    fn expr_is_msg_sender_call(&self, expr: &Expr) -> bool {
+        let Expr::FuncApp { func, .. } = expr else { return false };
+
+        func.span().as_str() == "std::auth::msg_sender" || self.msg_sender_names.iter().any(|name| func.span().as_str() == name)
+    }
+}
+
+#[derive(Default)]
+struct FnState {
+    is_abi_fn: bool,
+    var_sources: VarSources,
+}
+
+fn source_severity(source: ValueSource) -> Option<Severity> {
+    match source {
+        ValueSource::StorageRead => Some(Severity::High),
+        ValueSource::ExternalCall => Some(Severity::Medium),
+        ValueSource::MsgSender | ValueSource::Parameter => Some(Severity::Low),
+        _ => None,
+    }
+}
+
+fn classify_expr(module_state: &ModuleState, fn_state: &FnState, expr: &Expr, blocks: &[Span], scope: Rc<RefCell<AstScope>>) -> Option<ValueSource> {
+    fn_state.var_sources.classify_expr(expr, blocks, scope, &|expr| match expr {
+        Expr::FuncApp { .. } if module_state.expr_is_msg_sender_call(expr) => Some(ValueSource::MsgSender),
+
+        // Check if the method call is made on an abi cast, i.e: `abi(Test, id).f()`
+        Expr::MethodCall { target, .. } => match target.as_ref() {
+            Expr::AbiCast { .. } => Some(ValueSource::ExternalCall),
+
+            Expr::Path(_) => {
+                let var = fn_state.var_sources.find_var(target.span().as_str(), blocks)?;
+                var.is_abi.then_some(ValueSource::ExternalCall)
+            }
+
+            _ => None,
+        },
+
+        _ => None,
+    })
+}
+
+impl AstVisitor for UncheckedUnwrapVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::auth::msg_sender`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::auth::msg_sender") {
+            module_state.msg_sender_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.entry(fn_signature).or_default();
+
+        // Only functions implementing an abi for `Contract` are publicly accessible
+        if let Some(item_impl) = context.item_impl {
+            fn_state.is_abi_fn = item_impl.trait_opt.is_some() && item_impl.ty.span().as_str() == "Contract";
+        }
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check single variable patterns
+        let Pattern::AmbiguousSingleIdent(ident) = &context.statement_let.pattern else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Determine where the variable's value comes from
+        let source = classify_expr(module_state, fn_state, &context.statement_let.expr, context.blocks.as_slice(), scope);
+        let is_abi = matches!(&context.statement_let.expr, Expr::AbiCast { .. });
+
+        // Store the variable's source in the current block
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+        let block_span = context.blocks.last().unwrap();
+
+        fn_state.var_sources.add_var(block_span, ident.as_str(), source, is_abi);
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check `unwrap` and `expect` method calls
+        let Expr::MethodCall { target, path_seg, .. } = context.expr else { return Ok(()) };
+
+        if !matches!(path_seg.name.as_str(), "unwrap" | "expect") {
+            return Ok(());
+        }
+
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Only check publicly-accessible abi functions
+        if !fn_state.is_abi_fn {
+            return Ok(());
+        }
+
+        // Classify the receiver of the method call
+        let Some(source) = classify_expr(module_state, fn_state, target.as_ref(), context.blocks.as_slice(), scope) else { return Ok(()) };
+        let Some(severity) = source_severity(source) else { return Ok(()) };
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &context.expr.span())?,
+            severity,
+            format!(
+                "{} contains an unchecked `{}` on {}: `{}`. Consider handling the failure case explicitly.",
+                utils::get_item_location(context.item, &context.item_impl, &context.item_fn),
+                path_seg.name.as_str(),
+                source.description(),
+                context.expr.span().as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_unchecked_unwrap() {
+        crate::tests::test_detector("unchecked_unwrap", 5);
+    }
+}
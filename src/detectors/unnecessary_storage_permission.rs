@@ -0,0 +1,208 @@
+use crate::{
+    call_graph,
+    error::Error,
+    project::{PackageStates, Project},
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext},
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    path::PathBuf,
+    rc::Rc,
+};
+use sway_ast::{Expr, Pattern, Statement, StatementLet};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct UnnecessaryStoragePermissionVisitor {
+    package_states: PackageStates<PackageState>,
+}
+
+#[derive(Default)]
+struct PackageState {
+    fn_states: HashMap<String, FnState>,
+}
+
+#[derive(Clone)]
+struct FnState {
+    path: PathBuf,
+    line: Option<usize>,
+    location: String,
+    read_permission: bool,
+    write_permission: bool,
+    reads: bool,
+    writes: bool,
+    has_dead_read: bool,
+    storage_call_targets: Vec<Span>,
+    fn_call_candidates: Vec<Vec<String>>,
+    called_fns: Vec<String>,
+}
+
+/// Gets the name of the package function containing the expression in `context`, i.e: `Contract::f` or `my_module::f`
+fn get_fn_name(context: &ExprContext, project: &Project) -> Option<String> {
+    let item_fn = context.item_fn?;
+    let module_path = project.module_path(context.path);
+
+    Some(call_graph::get_fn_node_name(&module_path, context.item_impl, item_fn.fn_signature.name.as_str()))
+}
+
+/// Checks if `expr` is a value which is immediately discarded, i.e: `storage.x.read();` or `let _ = storage.x.read();`
+fn is_discarded_expr(expr: &Expr, statement: Option<&Statement>) -> bool {
+    match statement {
+        Some(Statement::Expr { expr: statement_expr, .. }) => statement_expr.span() == expr.span(),
+
+        Some(Statement::Let(StatementLet { pattern: Pattern::Wildcard { .. }, expr: let_expr, .. })) => let_expr.span() == expr.span(),
+
+        _ => false,
+    }
+}
+
+impl AstVisitor for UnnecessaryStoragePermissionVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get or create the package state
+        self.package_states.visit_module(project, context.path);
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        let module_path = project.module_path(context.path);
+        let fn_name = call_graph::get_fn_node_name(&module_path, context.item_impl, context.item_fn.fn_signature.name.as_str());
+        let line = project.span_to_line(context.path, &context.item_fn.fn_signature.span())?;
+
+        // Get the package state
+        let package_state = self.package_states.get_mut(context.path);
+
+        // Create the function state
+        package_state.fn_states.entry(fn_name).or_insert_with(|| FnState {
+            path: context.path.into(),
+            line,
+            location: utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+            read_permission: utils::check_attribute_decls(context.fn_attributes, "storage", &["read"]),
+            write_permission: utils::check_attribute_decls(context.fn_attributes, "storage", &["write"]),
+            reads: false,
+            writes: false,
+            has_dead_read: false,
+            storage_call_targets: vec![],
+            fn_call_candidates: vec![],
+            called_fns: vec![],
+        });
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let Some(fn_name) = get_fn_name(context, project) else { return Ok(()) };
+        let package_state = self.package_states.get_mut(context.path);
+        let Some(fn_state) = package_state.fn_states.get_mut(&fn_name) else { return Ok(()) };
+
+        match context.expr {
+            // Store the functions which may be called, since functions in other modules are resolved once the package has been visited
+            Expr::FuncApp { func, .. } => {
+                let module_path = project.module_path(context.path);
+                let candidates = call_graph::get_fn_call_candidates(context.module, &module_path, context.item_impl, func.as_ref());
+
+                if !candidates.is_empty() {
+                    fn_state.fn_call_candidates.push(candidates);
+                }
+            }
+
+            // Check for storage accesses, i.e: `storage.x.read()`
+            Expr::MethodCall { path_seg, .. } => {
+                let Some((_, writes)) = utils::get_storage_access(context.expr, &mut fn_state.storage_call_targets) else { return Ok(()) };
+
+                // Every storage method in `std` is declared with `#[storage(read)]` except for `clear`, i.e: `storage.x.get(k).clear()`
+                let reads = path_seg.name.as_str() != "clear";
+
+                // Reads whose values are immediately discarded do not require the `read` permission
+                if reads && !writes && is_discarded_expr(context.expr, context.statement) {
+                    fn_state.has_dead_read = true;
+                    return Ok(());
+                }
+
+                fn_state.reads |= reads;
+                fn_state.writes |= writes;
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check the package once all of its modules have been visited
+        let Some(mut package_state) = self.package_states.leave_module(context.path) else { return Ok(()) };
+
+        // Resolve the functions called by each function of the package
+        let fn_names = package_state.fn_states.keys().cloned().collect::<Vec<_>>();
+
+        for fn_state in package_state.fn_states.values_mut() {
+            fn_state.called_fns = fn_state.fn_call_candidates.iter()
+                .filter_map(|candidates| candidates.iter().find(|x| fn_names.contains(x)).cloned())
+                .collect();
+        }
+
+        // Propagate the storage accesses of called functions to the functions calling them
+        call_graph::propagate_fn_states(
+            &mut package_state.fn_states,
+            |fn_state| fn_state.called_fns.clone(),
+            |fn_state, called_fn_state| {
+                let (reads, writes) = (fn_state.reads || called_fn_state.reads, fn_state.writes || called_fn_state.writes);
+                let changed = reads != fn_state.reads || writes != fn_state.writes;
+
+                fn_state.reads = reads;
+                fn_state.writes = writes;
+
+                changed
+            },
+        );
+
+        let mut fn_states = package_state.fn_states.values().collect::<Vec<_>>();
+        fn_states.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+
+        // Check functions for unnecessary storage permissions
+        for fn_state in fn_states {
+            // Check for `#[storage(write)]` without any storage writes
+            if fn_state.write_permission && !fn_state.writes {
+                project.report.borrow_mut().add_entry(
+                    fn_state.path.clone(),
+                    fn_state.line,
+                    Severity::Low,
+                    format!(
+                        "{} is declared with the `write` storage permission but never writes to storage. Consider removing the `write` permission.",
+                        fn_state.location,
+                    ),
+                );
+            }
+
+            // Check for `#[storage(read)]` without any storage reads whose values are used
+            if fn_state.read_permission && !fn_state.reads {
+                project.report.borrow_mut().add_entry(
+                    fn_state.path.clone(),
+                    fn_state.line,
+                    Severity::Low,
+                    format!(
+                        "{} is declared with the `read` storage permission but {}. Consider removing the `read` permission.",
+                        fn_state.location,
+                        if fn_state.has_dead_read { "only discards the values it reads from storage" } else { "never reads from storage" },
+                    ),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_unnecessary_storage_permission() {
+        crate::tests::test_detector("unnecessary_storage_permission", 4);
+    }
+}
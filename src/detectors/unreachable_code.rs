@@ -0,0 +1,189 @@
+use crate::{
+    detectors::potential_infinite_loop::LoopAnalysis,
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{
+        AstVisitor, BlockContext, ExprContext, FnContext, ForExprContext, ModuleContext,
+        WhileExprContext,
+    },
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{
+    expr::LoopControlFlow,
+    literal::{LitBool, LitBoolType},
+    Expr, IfCondition, IfExpr, Literal, Statement,
+};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct UnreachableCodeVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    fn_states: HashMap<Span, FnState>,
+}
+
+#[derive(Default)]
+struct FnState {
+    loops: LoopAnalysis,
+}
+
+fn is_false_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::False, .. })))
+}
+
+fn is_true_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Literal(Literal::Bool(LitBool { kind: LitBoolType::True, .. })))
+}
+
+impl FnState {
+    /// Checks if execution can never continue past `expr`, returning a description of the diverging expression.
+    fn get_diverging_expr_description(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            // Check for `return`
+            Expr::Return { .. } => Some(expr.span().as_str().to_string()),
+
+            // Check for `revert(...)` and `require(false, ...)`
+            Expr::FuncApp { func, args } => {
+                let is_revert = matches!(func.span().as_str(), "revert" | "std::revert::revert");
+
+                let is_require_false = matches!(func.span().as_str(), "require" | "std::revert::require")
+                    && utils::fold_punctuated(&args.inner).first().map(|x| is_false_literal(x)).unwrap_or(false);
+
+                (is_revert || is_require_false).then(|| expr.span().as_str().to_string())
+            }
+
+            // Check for `if x { revert(...) } else { revert(...) }`
+            Expr::If(IfExpr { condition: IfCondition::Expr(condition), then_block, else_opt: Some((_, LoopControlFlow::Break(else_block))), .. }) => {
+                (utils::block_has_revert(then_block) && utils::block_has_revert(else_block))
+                    .then(|| format!("if {} {{ ... }} else {{ ... }}", condition.span().as_str()))
+            }
+
+            // Check for `while true { ... }` without a `break`
+            Expr::While { condition, block, .. } if is_true_literal(condition.as_ref()) => {
+                let loop_state = self.loops.get_loop_state(&block.span())?;
+
+                loop_state.is_potentially_infinite()
+                    .then(|| "while true { ... }".to_string())
+            }
+
+            _ => None,
+        }
+    }
+}
+
+impl AstVisitor for UnreachableCodeVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature).or_default();
+
+        Ok(())
+    }
+
+    fn leave_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get(&fn_signature).unwrap();
+
+        // Find the first statement which diverges
+        let mut statements = context.block.inner.statements.iter();
+
+        let Some(description) = statements.by_ref().find_map(|statement| {
+            let Statement::Expr { expr, .. } = statement else { return None };
+            fn_state.get_diverging_expr_description(expr)
+        }) else { return Ok(()) };
+
+        // Get the first unreachable statement or final expression following the diverging statement
+        let unreachable_span = match statements.next() {
+            Some(statement) => statement.span(),
+            None => match context.block.inner.final_expr_opt.as_ref() {
+                Some(expr) => expr.span(),
+                None => return Ok(()),
+            },
+        };
+
+        project.report.borrow_mut().add_entry(
+            context.path,
+            project.span_to_line(context.path, &unreachable_span)?,
+            Severity::Low,
+            format!(
+                "{} contains unreachable code after `{}`: `{}`. Consider removing the unreachable code.",
+                utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                description,
+                unreachable_span.as_str(),
+            ),
+        );
+
+        Ok(())
+    }
+
+    fn visit_while_expr(&mut self, context: &WhileExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.loops.visit_while_expr(context);
+
+        Ok(())
+    }
+
+    fn visit_for_expr(&mut self, context: &ForExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.loops.visit_for_expr(context);
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        fn_state.loops.visit_expr(context);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_unreachable_code() {
+        crate::tests::test_detector("unreachable_code", 5);
+    }
+}
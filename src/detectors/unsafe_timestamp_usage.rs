@@ -23,18 +23,8 @@ struct ModuleState {
 
 impl ModuleState {
     fn expr_is_timestamp_call(&self, expr: &Expr) -> bool {
-        // Destructure the expression into a function application
-        let Expr::FuncApp { func, .. } = expr else { return false };
-        let Expr::Path(path) = func.as_ref() else { return false };
-        
-        // Update the variable state
-        match path.span().as_str() {
-            "std::block::timestamp" => true,
-            "std::block::timestamp_of_block" => true,
-            s if self.timestamp_names.iter().any(|name| s == name) => true,
-            s if self.timestamp_of_block_names.iter().any(|name| s == name) => true,
-            _ => false,
-        }
+        utils::is_fn_call(expr, "std::block::timestamp", self.timestamp_names.as_slice())
+            || utils::is_fn_call(expr, "std::block::timestamp_of_block", self.timestamp_of_block_names.as_slice())
     }
 }
 
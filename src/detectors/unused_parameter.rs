@@ -0,0 +1,189 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, FnArgs};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct UnusedParameterVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    fn_states: HashMap<Span, FnState>,
+}
+
+#[derive(Default)]
+struct FnState {
+    parameter_states: Vec<ParameterState>,
+}
+
+impl FnState {
+    fn check_name_usage(&mut self, name: &str) {
+        if let Some(parameter_state) = self.parameter_states.iter_mut().find(|p| p.name == name) {
+            parameter_state.used = true;
+        }
+    }
+}
+
+struct ParameterState {
+    name: String,
+    ty_name: String,
+    span: Span,
+    used: bool,
+}
+
+impl ParameterState {
+    /// Checks if the parameter is an identity or an amount which would usually need to be validated.
+    fn is_sensitive(&self) -> bool {
+        match self.ty_name.as_str() {
+            "Identity" | "Address" | "ContractId" | "AssetId" => true,
+
+            "u64" => ["amount", "balance", "fee", "price", "quantity", "value"]
+                .iter()
+                .any(|x| self.name.to_lowercase().contains(x)),
+
+            _ => false,
+        }
+    }
+}
+
+impl AstVisitor for UnusedParameterVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Skip functions implemented for traits other than contract ABIs, since their parameters are required by the trait
+        if let Some(item_impl) = context.item_impl.as_ref() {
+            if item_impl.trait_opt.is_some() && item_impl.ty.span().as_str() != "Contract" {
+                return Ok(());
+            }
+        }
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.entry(fn_signature).or_default();
+
+        // Store the function's parameters
+        let args = match &context.item_fn.fn_signature.arguments.inner {
+            FnArgs::Static(args) => args,
+            FnArgs::NonStatic { args_opt: Some(args), .. } => &args.1,
+            _ => return Ok(()),
+        };
+
+        for arg in args {
+            for ident in utils::fold_pattern_idents(&arg.pattern) {
+                // Skip parameters which are explicitly unused, i.e: `_amount`
+                if ident.as_str().starts_with('_') {
+                    continue;
+                }
+
+                fn_state.parameter_states.push(ParameterState {
+                    name: ident.as_str().to_string(),
+                    ty_name: utils::ty_to_string(&arg.ty),
+                    span: ident.span(),
+                    used: false,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn leave_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let Some(fn_state) = module_state.fn_states.get(&fn_signature) else { return Ok(()) };
+
+        // Check if the function is part of a contract ABI
+        let is_abi_fn = context.item_impl.as_ref()
+            .map(|item_impl| item_impl.trait_opt.is_some() && item_impl.ty.span().as_str() == "Contract")
+            .unwrap_or(false);
+
+        for parameter_state in fn_state.parameter_states.iter() {
+            if parameter_state.used {
+                continue;
+            }
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &parameter_state.span)?,
+                if is_abi_fn && parameter_state.is_sensitive() { Severity::Medium } else { Severity::Low },
+                format!(
+                    "{} does not use its `{}: {}` parameter. Consider validating the parameter, removing it or prefixing its name with an underscore.",
+                    utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                    parameter_state.name,
+                    parameter_state.ty_name,
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let Some(item_fn) = context.item_fn.as_ref() else { return Ok(()) };
+        let fn_signature = item_fn.fn_signature.span();
+        let Some(fn_state) = module_state.fn_states.get_mut(&fn_signature) else { return Ok(()) };
+
+        match context.expr {
+            // Check for variable usage, i.e: `amount`
+            Expr::Path(path) if path.root_opt.is_none() && path.suffix.is_empty() => {
+                fn_state.check_name_usage(path.prefix.name.as_str());
+            }
+
+            // Check for struct field shorthand usage, i.e: `Transfer { amount }`
+            Expr::Struct { fields, .. } => {
+                for field in &fields.inner {
+                    if field.expr_opt.is_none() {
+                        fn_state.check_name_usage(field.field_name.as_str());
+                    }
+                }
+            }
+
+            // Check for asm register shorthand usage, i.e: `asm(amount) { ... }`
+            Expr::Asm(asm_block) => {
+                for register in &asm_block.registers.inner {
+                    if register.value_opt.is_none() {
+                        fn_state.check_name_usage(register.register.as_str());
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_unused_parameter() {
+        crate::tests::test_detector("unused_parameter", 3);
+    }
+}
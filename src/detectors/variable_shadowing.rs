@@ -0,0 +1,164 @@
+use crate::{
+    error::Error,
+    project::Project,
+    report::Severity,
+    scope::{AstScope, AstVariableKind},
+    utils,
+    visitor::{AstVisitor, BlockContext, FnContext, ModuleContext, StatementLetContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct VariableShadowingVisitor {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    fn_states: HashMap<Span, FnState>,
+}
+
+#[derive(Default)]
+struct FnState {
+    block_states: HashMap<Span, BlockState>,
+}
+
+impl FnState {
+    fn is_local_variable(&self, name: &str, blocks: &[Span]) -> bool {
+        blocks.iter().any(|block_span| {
+            let block_state = self.block_states.get(block_span).unwrap();
+            block_state.variable_names.iter().any(|variable_name| variable_name == name)
+        })
+    }
+}
+
+#[derive(Default)]
+struct BlockState {
+    variable_names: Vec<String>,
+}
+
+/// Checks if `code` contains an access to the `name` storage field, i.e: `storage.name`
+fn code_accesses_storage_field(code: &str, name: &str) -> bool {
+    let pattern = format!("storage.{name}");
+
+    code.match_indices(pattern.as_str()).any(|(index, _)| {
+        let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+        let before = code[..index].chars().next_back();
+        let after = code[index + pattern.len()..].chars().next();
+
+        !before.map(is_ident_char).unwrap_or(false) && !after.map(is_ident_char).unwrap_or(false)
+    })
+}
+
+impl AstVisitor for VariableShadowingVisitor {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        if !self.module_states.contains_key(context.path) {
+            self.module_states.insert(context.path.into(), ModuleState::default());
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Create the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+
+        module_state.fn_states.entry(fn_signature).or_default();
+
+        Ok(())
+    }
+
+    fn visit_block(&mut self, context: &BlockContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        // Create the block state
+        let block_span = context.block.span();
+
+        fn_state.block_states.entry(block_span).or_default();
+
+        Ok(())
+    }
+
+    fn visit_statement_let(&mut self, context: &StatementLetContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Get the function state
+        let fn_signature = context.item_fn.fn_signature.span();
+        let fn_state = module_state.fn_states.get_mut(&fn_signature).unwrap();
+
+        for ident in utils::fold_pattern_idents(&context.statement_let.pattern) {
+            let name = ident.as_str().to_string();
+
+            // Only report the first declaration that shadows a binding, since subsequent declarations shadow a local variable
+            if fn_state.is_local_variable(name.as_str(), context.blocks.as_slice()) {
+                continue;
+            }
+
+            // Find the closest binding declared outside of the function body
+            let shadowed_variable = scope.borrow().get_variables(name.as_str(), false).into_iter()
+                .find(|variable| variable.borrow().span != ident.span() && variable.borrow().kind != AstVariableKind::Local)
+                .or_else(|| {
+                    // Only consider storage fields which are accessed by the function, i.e: `storage.owner.read()`
+                    let variable = scope.borrow().get_variable(name.as_str(), true)?;
+                    code_accesses_storage_field(context.item_fn.body.span().as_str(), name.as_str()).then_some(variable)
+                });
+
+            // Get the current block state
+            let block_span = context.blocks.last().unwrap();
+            let block_state = fn_state.block_states.get_mut(block_span).unwrap();
+
+            block_state.variable_names.push(name.clone());
+
+            let Some(shadowed_variable) = shadowed_variable else { continue };
+            let shadowed_variable = shadowed_variable.borrow();
+
+            // Only report bindings declared in the current module
+            if shadowed_variable.span.src() != ident.span().src() {
+                continue;
+            }
+
+            let kind = match shadowed_variable.kind {
+                AstVariableKind::Constant => "constant",
+                AstVariableKind::Storage => "storage field",
+                AstVariableKind::Configurable => "configurable",
+                AstVariableKind::Parameter => "parameter",
+                AstVariableKind::Local => continue,
+            };
+
+            project.report.borrow_mut().add_entry(
+                context.path,
+                project.span_to_line(context.path, &ident.span())?,
+                Severity::Low,
+                format!(
+                    "{} declares the `{}` variable which shadows the `{}` {} declared on L{}. Consider renaming the variable.",
+                    utils::get_item_location(context.item, &context.item_impl, &Some(context.item_fn)),
+                    name,
+                    name,
+                    kind,
+                    project.span_to_line(context.path, &shadowed_variable.span)?.map(|line| line.to_string()).unwrap_or_else(|| "?".into()),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_variable_shadowing() {
+        crate::tests::test_detector("variable_shadowing", 3);
+    }
+}
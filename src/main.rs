@@ -1,16 +1,21 @@
 #![allow(clippy::type_complexity)]
+pub mod abi_diff;
+pub mod call_graph;
 pub mod detectors;
 pub mod error;
+pub mod printers;
 pub mod project;
 pub mod report;
 pub mod scope;
+pub mod storage_diff;
+pub mod summary;
 pub mod utils;
 pub mod visitor;
 
 use error::Error;
 use project::{DisplayFormat, Project};
 use report::Sorting;
-use std::{collections::HashSet, path::PathBuf};
+use std::{collections::HashSet, path::{Path, PathBuf}};
 use structopt::{clap::AppSettings, StructOpt};
 
 #[derive(Default, StructOpt)]
@@ -36,6 +41,10 @@ struct Options {
     #[structopt(long)]
     detectors: Vec<String>,
 
+    /// The specific printers to utilize instead of the detectors. (Optional)
+    #[structopt(long)]
+    print: Vec<String>,
+
     /// The severity levels to `include` in the report. Default is all of the severities and options are `low` `medium` and `high` (Optional)
     #[structopt(long)]
     include: Vec<String>,
@@ -43,6 +52,18 @@ struct Options {
     /// The severity levels to `exclude` from the report. Default is none of the severities and options are `low` `medium` and `high` (Optional)
     #[structopt(long)]
     exclude: Vec<String>,
+
+    /// The paths to the old and new Forc project directories to compare the storage layouts of. (Optional)
+    #[structopt(long, number_of_values = 2, value_names = &["OLD", "NEW"])]
+    diff_storage: Vec<PathBuf>,
+
+    /// The paths to the old and new Forc project directories to compare the ABI declarations of. (Optional)
+    #[structopt(long, number_of_values = 2, value_names = &["OLD", "NEW"])]
+    diff_abi: Vec<PathBuf>,
+
+    /// The path to write the call graph of the project to. Written as Graphviz DOT if the path ends with `.dot`, otherwise as JSON. (Optional)
+    #[structopt(long)]
+    call_graph: Option<PathBuf>,
 }
 
 fn main() -> Result<(), Error> {
@@ -53,6 +74,11 @@ fn main() -> Result<(), Error> {
         return Err(Error::Wrapped("Cannot use both include and exclude options.".into()));
     }
 
+    // Make sure that we have a valid print or detectors input. Both are not allowed.
+    if !options.print.is_empty() && !options.detectors.is_empty() {
+        return Err(Error::Wrapped("Cannot use both print and detectors options.".into()));
+    }
+
     // Make sure directory is a directory path
     if let Some(directory) = options.directory.as_mut() {
         let dir_string = directory.to_string_lossy();
@@ -62,11 +88,31 @@ fn main() -> Result<(), Error> {
         }
     }
 
+    // Compare the storage layouts of two versions of a project instead of analyzing a project
+    if !options.diff_storage.is_empty() {
+        let project = diff_storage(&options)?;
+        println!("{project}");
+        return Ok(());
+    }
+
+    // Compare the ABI declarations of two versions of a project instead of analyzing a project
+    if !options.diff_abi.is_empty() {
+        let project = diff_abi(&options)?;
+        println!("{project}");
+        return Ok(());
+    }
+
     // Make sure that we have a target to analyze (either a directory or files)
     if options.directory.is_none() && options.files.is_empty() {
         return Err(Error::Wrapped("No directory or files provided as a target to analyze.".into()));
     }
 
+    // Export the call graph of the project instead of analyzing the project
+    if let Some(path) = options.call_graph.as_ref() {
+        let mut project = Project::try_from(&options)?;
+        return export_call_graph(&mut project, path);
+    }
+
     // Construct the project and analyze the modules
     let mut project = Project::try_from(&options)?;
     project.analyze_modules()?;
@@ -80,6 +126,61 @@ fn main() -> Result<(), Error> {
     Ok(())
 }
 
+/// Compare the storage layouts of the old and new project directories supplied to the `diff_storage` option
+fn diff_storage(options: &Options) -> Result<Project, Error> {
+    diff_projects(options, &options.diff_storage, "storage layouts", storage_diff::diff_storage_layouts)
+}
+
+/// Compare the ABI declarations of the old and new project directories supplied to the `diff_abi` option
+fn diff_abi(options: &Options) -> Result<Project, Error> {
+    diff_projects(options, &options.diff_abi, "ABI declarations", abi_diff::diff_abi_layouts)
+}
+
+/// Construct projects from the old and new `directories` and compare them using `diff`, returning the new project with the entries it reported
+fn diff_projects<'a>(
+    options: &Options,
+    directories: &[PathBuf],
+    description: &str,
+    diff: fn(&mut Project, &mut Project) -> Result<(), Error>,
+) -> Result<Project<'a>, Error> {
+    let [old_directory, new_directory] = directories else {
+        return Err(Error::Wrapped(format!("Expected an old and a new directory to compare the {description} of.").into()));
+    };
+
+    let mut old_project = Project::try_from(&Options {
+        directory: Some(old_directory.clone()),
+        ..Default::default()
+    })?;
+
+    let mut new_project = Project::try_from(&Options {
+        display_format: options.display_format,
+        sorting: options.sorting,
+        directory: Some(new_directory.clone()),
+        ..Default::default()
+    })?;
+
+    diff(&mut old_project, &mut new_project)?;
+
+    // Filter the entries based on the include or exclude options
+    let entries = filter_entries(&new_project.report.borrow(), options);
+    new_project.report.borrow_mut().entries = entries.into_iter().collect();
+
+    Ok(new_project)
+}
+
+/// Write the call graph of the project to the path supplied to the `call_graph` option
+fn export_call_graph(project: &mut Project, path: &Path) -> Result<(), Error> {
+    let call_graph = call_graph::call_graph(project)?;
+
+    let output = if path.extension().map(|x| x == "dot").unwrap_or(false) {
+        call_graph.to_dot()
+    } else {
+        serde_json::to_string_pretty(&call_graph).map_err(|e| Error::Wrapped(Box::new(e)))?
+    };
+
+    std::fs::write(path, output).map_err(|e| Error::Wrapped(Box::new(e)))
+}
+
 /// Filter the entries based on the include or exclude options
 fn filter_entries(report: &crate::report::Report, options: &Options) -> Vec<(PathBuf, Vec<crate::report::Entry>)> {
     let mut out = vec![];
@@ -114,6 +215,7 @@ fn filter_entries(report: &crate::report::Report, options: &Options) -> Vec<(Pat
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::{call_graph::CallGraphNodeKind, summary::Summary};
 
     pub fn test_detector(name: &str, entry_count: usize) {
         let options = Options {
@@ -142,6 +244,48 @@ pub mod tests {
         }
     }
 
+    pub fn test_printer(name: &str, row_count: usize) -> Summary {
+        let options = Options {
+            directory: Some(format!("test/{name}").into()),
+            print: vec![name.to_string()],
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        project.analyze_modules().unwrap();
+
+        println!("{project}");
+
+        let summary = project.summary.borrow().clone();
+
+        let actual_row_count = summary.tables.iter()
+            .filter(|table| table.printer == name)
+            .map(|table| table.rows.len())
+            .sum::<usize>();
+
+        if row_count != actual_row_count {
+            panic!(
+                "Expected {row_count} {}, found {actual_row_count} {}",
+                if row_count == 1 { "row" } else { "rows" },
+                if actual_row_count == 1 { "row" } else { "rows" },
+            );
+        }
+
+        summary
+    }
+
+    /// Gets the cell in the `column` column of the row whose `key_column` cell is `key` in the table titled `title`.
+    pub fn get_cell(summary: &Summary, title: &str, key_column: &str, key: &str, column: &str) -> Option<String> {
+        summary.tables.iter()
+            .filter(|table| table.title == title)
+            .find_map(|table| {
+                let key_index = table.columns.iter().position(|x| x == key_column)?;
+                let index = table.columns.iter().position(|x| x == column)?;
+                let row = table.rows.iter().find(|row| row.get(key_index).is_some_and(|x| x == key))?;
+                row.get(index).cloned()
+            })
+    }
+
     #[test]
     fn test_detectors() {
         let options = Options {
@@ -286,4 +430,94 @@ pub mod tests {
 
         println!("{project}");
     }
+
+    #[test]
+    fn test_diff_storage() {
+        // The new version of the diff_storage project removes 1 field, reorders 2 fields,
+        // retypes 1 field and re-keys 1 field. Writing the same key with different zero-padding is not a change,
+        // so we should see 5 entries
+        let options = Options {
+            diff_storage: vec![PathBuf::from("test/diff_storage/old"), PathBuf::from("test/diff_storage/new")],
+            ..Default::default()
+        };
+
+        let project = diff_storage(&options).unwrap();
+
+        let entry_count = project.report.borrow().entries.iter().map(|(_, entries)| entries.len()).sum::<usize>();
+        assert_eq!(entry_count, 5);
+
+        println!("{project}");
+    }
+
+    #[test]
+    fn test_diff_storage_namespace() {
+        // The new version of the diff_storage_namespace project moves both of its fields into a namespace,
+        // which changes their storage keys, so we should see 2 high severity entries
+        let options = Options {
+            diff_storage: vec![PathBuf::from("test/diff_storage_namespace/old"), PathBuf::from("test/diff_storage_namespace/new")],
+            ..Default::default()
+        };
+
+        let project = diff_storage(&options).unwrap();
+
+        let entries = project.report.borrow().entries.iter().flat_map(|(_, entries)| entries.clone()).collect::<Vec<_>>();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|entry| entry.severity == crate::report::Severity::High));
+
+        println!("{project}");
+    }
+
+    #[test]
+    fn test_diff_abi() {
+        // The new version of the diff_abi project renames the ABI, removes 1 function and 1 default function, changes the signatures of 2 functions,
+        // changes the storage access and payability of 1 function and stops logging 1 type. We should see all 8 entries
+        let options = Options {
+            diff_abi: vec![PathBuf::from("test/diff_abi/old"), PathBuf::from("test/diff_abi/new")],
+            ..Default::default()
+        };
+
+        let project = diff_abi(&options).unwrap();
+
+        let entry_count = project.report.borrow().entries.iter().map(|(_, entries)| entries.len()).sum::<usize>();
+        assert_eq!(entry_count, 8);
+
+        println!("{project}");
+    }
+
+    #[test]
+    fn test_call_graph() {
+        // The call_graph project declares 8 functions across 3 modules, calls 3 std functions and 1 library function,
+        // makes 1 external call and calls 1 method on a value of unknown type. We should see all 14 nodes and the 11 calls between them
+        let options = Options {
+            directory: Some(PathBuf::from("test/call_graph")),
+            ..Default::default()
+        };
+
+        let mut project = Project::try_from(&options).unwrap();
+        let call_graph = crate::call_graph::call_graph(&mut project).unwrap();
+
+        println!("{}", call_graph.to_dot());
+
+        assert_eq!(call_graph.nodes.len(), 14);
+        assert_eq!(call_graph.edges.len(), 11);
+
+        let has_edge = |caller: &str, callee: &str| call_graph.edges.iter().any(|x| x.caller == caller && x.callee == callee);
+        let get_kind = |name: &str| call_graph.get_node(name).map(|x| x.kind);
+
+        // Functions with the same name in different modules are separate nodes
+        assert!(has_edge("get_sender", "lib::get_sender"));
+        assert!(has_edge("lib::get_sender", "msg_sender"));
+        assert!(has_edge("Contract::deposit", "get_sender"));
+
+        // Calls to functions of generic impls are resolved through imports and the types of variables
+        assert!(has_edge("Contract::quote", "fee::Fee::new"));
+        assert!(has_edge("Contract::quote", "fee::Fee::apply"));
+        assert!(has_edge("Contract::quote", "Oracle::price"));
+
+        // Functions which are not declared in the package are classified by where they come from
+        assert_eq!(get_kind("msg_sender"), Some(CallGraphNodeKind::Std));
+        assert_eq!(get_kind("ContractId::into"), Some(CallGraphNodeKind::Std));
+        assert_eq!(get_kind("only_owner"), Some(CallGraphNodeKind::Library));
+        assert_eq!(get_kind("_::unwrap"), Some(CallGraphNodeKind::UnresolvedMethod));
+    }
 }
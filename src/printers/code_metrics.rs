@@ -0,0 +1,307 @@
+use crate::{
+    error::Error,
+    project::Project,
+    scope::AstScope,
+    utils,
+    visitor::{
+        AstVisitor, ExprContext, FnContext, ForExprContext, IfExprContext, MatchExprContext,
+        ModuleContext, WhileExprContext,
+    },
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{Expr, ItemFn};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct CodeMetricsPrinter {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    fn_states: Vec<FnState>,
+    storage_call_targets: Vec<Span>,
+}
+
+struct FnState {
+    fn_span: Span,
+    name: String,
+    complexity: usize,
+    nesting_depth: usize,
+    max_nesting_depth: usize,
+    storage_accesses: usize,
+    external_calls: usize,
+    lines: usize,
+}
+
+impl ModuleState {
+    /// Gets the function state of `item_fn`
+    fn fn_state_mut(&mut self, item_fn: &ItemFn) -> Option<&mut FnState> {
+        let fn_span = item_fn.fn_signature.span();
+        self.fn_states.iter_mut().find(|x| x.fn_span == fn_span)
+    }
+}
+
+impl FnState {
+    fn enter_nested_expr(&mut self) {
+        self.nesting_depth += 1;
+        self.max_nesting_depth = self.max_nesting_depth.max(self.nesting_depth);
+    }
+
+    fn leave_nested_expr(&mut self) {
+        self.nesting_depth -= 1;
+    }
+}
+
+impl AstVisitor for CodeMetricsPrinter {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        self.module_states.insert(context.path.into(), ModuleState::default());
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        let name = match context.item_impl.as_ref() {
+            Some(item_impl) => format!("{}::{}", item_impl.ty.span().as_str(), context.item_fn.fn_signature.name.as_str()),
+            None => context.item_fn.fn_signature.name.as_str().to_string(),
+        };
+
+        // Create the function state
+        module_state.fn_states.push(FnState {
+            fn_span: context.item_fn.fn_signature.span(),
+            name,
+            complexity: 1,
+            nesting_depth: 0,
+            max_nesting_depth: 0,
+            storage_accesses: 0,
+            external_calls: 0,
+            lines: context.item_fn.span().as_str().lines().count(),
+        });
+
+        Ok(())
+    }
+
+    fn visit_if_expr(&mut self, context: &IfExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        fn_state.complexity += 1;
+
+        // An `else if` branch is visited as a separate if expression at the same level of nesting
+        if context.if_expr.span() == context.expr.span() {
+            fn_state.enter_nested_expr();
+        }
+
+        Ok(())
+    }
+
+    fn leave_if_expr(&mut self, context: &IfExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        if context.if_expr.span() == context.expr.span() {
+            fn_state.leave_nested_expr();
+        }
+
+        Ok(())
+    }
+
+    fn visit_match_expr(&mut self, context: &MatchExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        // Each branch after the first one adds a path through the function
+        fn_state.complexity += context.branches.inner.len().saturating_sub(1);
+        fn_state.enter_nested_expr();
+
+        Ok(())
+    }
+
+    fn leave_match_expr(&mut self, context: &MatchExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        fn_state.leave_nested_expr();
+
+        Ok(())
+    }
+
+    fn visit_while_expr(&mut self, context: &WhileExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        fn_state.complexity += 1;
+        fn_state.enter_nested_expr();
+
+        Ok(())
+    }
+
+    fn leave_while_expr(&mut self, context: &WhileExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        fn_state.leave_nested_expr();
+
+        Ok(())
+    }
+
+    fn visit_for_expr(&mut self, context: &ForExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        fn_state.complexity += 1;
+        fn_state.enter_nested_expr();
+
+        Ok(())
+    }
+
+    fn leave_for_expr(&mut self, context: &ForExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the function state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+        let Some(fn_state) = module_state.fn_state_mut(context.item_fn) else { return Ok(()) };
+
+        fn_state.leave_nested_expr();
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check expressions in functions
+        let Some(item_fn) = context.item_fn else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        match context.expr {
+            // Each short-circuiting operator adds a path through the function
+            Expr::LogicalAnd { .. } | Expr::LogicalOr { .. } => {
+                let Some(fn_state) = module_state.fn_state_mut(item_fn) else { return Ok(()) };
+                fn_state.complexity += 1;
+            }
+
+            Expr::MethodCall { target, .. } => {
+                // Check for storage accesses, i.e: `storage.x.read()`
+                if utils::get_storage_access(context.expr, &mut module_state.storage_call_targets).is_some() {
+                    let Some(fn_state) = module_state.fn_state_mut(item_fn) else { return Ok(()) };
+                    fn_state.storage_accesses += 1;
+
+                    return Ok(());
+                }
+
+                let is_external_call = match target.as_ref() {
+                    // Check for external calls on abi casts, i.e: `abi(MyAbi, id).f()`
+                    Expr::AbiCast { .. } => true,
+
+                    // Check for external calls on abi variables, i.e: `let x = abi(MyAbi, id); x.f()`
+                    Expr::Path(path) if path.suffix.is_empty() => {
+                        scope.borrow().get_variable(path.prefix.name.as_str(), false).map(|variable| {
+                            let ty_name = utils::ty_to_string(&variable.borrow().ty);
+                            scope.borrow().find_abi(|x| x.borrow().name.as_str() == ty_name).is_some()
+                        }).unwrap_or(false)
+                    }
+
+                    _ => false,
+                };
+
+                if is_external_call {
+                    let Some(fn_state) = module_state.fn_state_mut(item_fn) else { return Ok(()) };
+                    fn_state.external_calls += 1;
+                }
+            }
+
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let Some(module_state) = self.module_states.remove(context.path) else { return Ok(()) };
+
+        if module_state.fn_states.is_empty() {
+            return Ok(());
+        }
+
+        let rows = module_state.fn_states.iter()
+            .map(|fn_state| vec![
+                fn_state.name.clone(),
+                fn_state.complexity.to_string(),
+                fn_state.max_nesting_depth.to_string(),
+                fn_state.storage_accesses.to_string(),
+                fn_state.external_calls.to_string(),
+                fn_state.lines.to_string(),
+            ])
+            .collect::<Vec<_>>();
+
+        project.summary.borrow_mut().add_table(
+            "code_metrics",
+            Some(context.path),
+            "Code Metrics",
+            &["Function", "Complexity", "Nesting", "Storage Accesses", "External Calls", "Lines"],
+            rows,
+        );
+
+        // Add the aggregates of the module as labeled values, since they are not all sums of the function metrics
+        let fn_states = &module_state.fn_states;
+
+        let aggregates = [
+            ("Functions", fn_states.len()),
+            ("Total Complexity", fn_states.iter().map(|x| x.complexity).sum::<usize>()),
+            ("Max Nesting", fn_states.iter().map(|x| x.max_nesting_depth).max().unwrap_or_default()),
+            ("Total Storage Accesses", fn_states.iter().map(|x| x.storage_accesses).sum::<usize>()),
+            ("Total External Calls", fn_states.iter().map(|x| x.external_calls).sum::<usize>()),
+            ("Total Lines", fn_states.iter().map(|x| x.lines).sum::<usize>()),
+        ];
+
+        project.summary.borrow_mut().add_table(
+            "code_metrics",
+            Some(context.path),
+            "Code Metrics Totals",
+            &["Metric", "Value"],
+            aggregates.iter().map(|(metric, value)| vec![metric.to_string(), value.to_string()]).collect(),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_code_metrics() {
+        // 4 function rows and 6 aggregate rows
+        let summary = crate::tests::test_printer("code_metrics", 10);
+
+        let get_metric = |function: &str, column: &str| {
+            crate::tests::get_cell(&summary, "Code Metrics", "Function", function, column).unwrap()
+        };
+
+        assert_eq!(get_metric("get_tier", "Complexity"), "3");
+        assert_eq!(get_metric("sum", "Complexity"), "4");
+        assert_eq!(get_metric("sum", "Nesting"), "2");
+        assert_eq!(get_metric("Contract::deposit", "Storage Accesses"), "4");
+        assert_eq!(get_metric("Contract::fee_for", "Complexity"), "3");
+        assert_eq!(get_metric("Contract::fee_for", "External Calls"), "1");
+
+        let get_total = |metric: &str| {
+            crate::tests::get_cell(&summary, "Code Metrics Totals", "Metric", metric, "Value").unwrap()
+        };
+
+        assert_eq!(get_total("Functions"), "4");
+        assert_eq!(get_total("Total Complexity"), "11");
+        assert_eq!(get_total("Max Nesting"), "2");
+        assert_eq!(get_total("Total Storage Accesses"), "5");
+    }
+}
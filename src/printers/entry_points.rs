@@ -0,0 +1,425 @@
+use crate::{
+    abi_diff,
+    call_graph,
+    error::Error,
+    project::{PackageStates, Project},
+    scope::AstScope,
+    utils,
+    visitor::{AbiContext, AstVisitor, ExprContext, FnContext, ModuleContext, UseContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_ast::{AttributeDecl, Expr, IfCondition, ItemImpl, ItemKind, Module, ModuleKind, Statement};
+use sway_types::Spanned;
+
+#[derive(Default)]
+pub struct EntryPointsPrinter {
+    package_states: PackageStates<PackageState>,
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct PackageState {
+    fn_states: HashMap<String, FnState>,
+}
+
+struct ModuleState {
+    log_names: Vec<String>,
+}
+
+impl Default for ModuleState {
+    fn default() -> Self {
+        Self {
+            // Since `std::logging::log` is part of the prelude, include it here
+            log_names: vec!["log".into(), "std::logging::log".into()],
+        }
+    }
+}
+
+#[derive(Clone)]
+struct FnState {
+    path: PathBuf,
+    line: Option<usize>,
+    entry_point_name: Option<String>,
+    attributes: Vec<String>,
+    guards: Vec<String>,
+    assets: Vec<String>,
+    external_calls: Vec<String>,
+    logs: Vec<String>,
+    fn_calls: Vec<FnCall>,
+    called_fns: Vec<String>,
+}
+
+#[derive(Clone)]
+struct FnCall {
+    /// The names of the package functions which may be called, in order of precedence
+    candidates: Vec<String>,
+    /// The call itself when it looks like an access control guard function, i.e: `only_owner()`
+    guard: Option<String>,
+}
+
+/// The asset functions which transfer, mint or burn native assets, along with the index of their asset argument
+const ASSET_FNS: &[(&str, usize)] = &[
+    ("transfer", 1),
+    ("transfer_to_address", 1),
+    ("force_transfer_to_contract", 1),
+    ("mint", 0),
+    ("mint_to", 1),
+    ("mint_to_address", 1),
+    ("mint_to_contract", 1),
+    ("burn", 0),
+];
+
+/// Adds `value` to `values` if it has not been added already
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+/// Gets the attributes of a function which are relevant to its callers, i.e: `storage(read, write), payable`
+fn get_fn_attributes(fn_attributes: &[AttributeDecl]) -> Vec<String> {
+    let mut attributes = vec![];
+
+    // Get the storage attribute, i.e: `#[storage(read, write)]`
+    let storage_access = fn_attributes.iter()
+        .flat_map(|attribute_decl| utils::fold_punctuated(&attribute_decl.attribute.inner))
+        .filter(|attribute| attribute.name.as_str() == "storage")
+        .filter_map(|attribute| attribute.args.as_ref())
+        .flat_map(|args| utils::fold_punctuated(&args.inner).iter().map(|arg| arg.name.as_str().to_string()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    if !storage_access.is_empty() {
+        attributes.push(format!("storage({})", storage_access.join(", ")));
+    }
+
+    if utils::check_attribute_decls(fn_attributes, "payable", &[]) {
+        attributes.push("payable".into());
+    }
+
+    attributes
+}
+
+impl FnState {
+    /// Checks `expr` for access control guards, asset functions, external calls, logged events and calls to other functions
+    fn check_expr(
+        &mut self,
+        expr: &Expr,
+        module: &Module,
+        module_path: &[String],
+        item_impl: Option<&ItemImpl>,
+        module_state: &ModuleState,
+        scope: &Rc<RefCell<AstScope>>,
+    ) {
+        // Check for access control guards via `require` or `if/revert`, i.e: `require(msg_sender().unwrap() == owner, ...)`
+        let condition = if let Some(args) = utils::get_require_args(expr) {
+            args.first().cloned()
+        } else if let Some(IfCondition::Expr(condition)) = utils::get_if_revert_condition(expr) {
+            Some(condition.as_ref())
+        } else {
+            None
+        };
+
+        if let Some(condition) = condition {
+            if condition.span().as_str().contains("msg_sender") {
+                push_unique(&mut self.guards, condition.span().as_str().to_string());
+            }
+
+            return;
+        }
+
+        match expr {
+            Expr::FuncApp { func, args } => {
+                let func_name = func.span().as_str().to_string();
+                let fn_name = func_name.rsplit("::").next().unwrap_or_default();
+                let args = utils::fold_punctuated(&args.inner);
+
+                // Store the functions which may be called, since their effects are added once the package has been visited
+                let candidates = call_graph::get_fn_call_candidates(module, module_path, item_impl, func.as_ref());
+
+                // Check for access control guard functions, i.e: `only_owner()`
+                let guard = fn_name.starts_with("only_").then(|| expr.span().as_str().to_string());
+
+                if !candidates.is_empty() || guard.is_some() {
+                    self.fn_calls.push(FnCall { candidates, guard });
+                }
+
+                // Check for asset transfers, mints and burns, i.e: `transfer(to, asset_id, amount)`
+                if let Some((_, asset_arg_index)) = ASSET_FNS.iter().find(|(name, _)| *name == fn_name) {
+                    if let Some(asset_arg) = args.get(*asset_arg_index) {
+                        push_unique(&mut self.assets, format!("{fn_name}({})", asset_arg.span().as_str()));
+                    }
+                }
+
+                // Check for logged events, i.e: `log(Transfer { ... })` or `emit(Transfer { ... })` for `use std::logging::log as emit;`
+                if module_state.log_names.contains(&func_name) {
+                    if let Some(arg) = args.first() {
+                        self.add_log(arg);
+                    }
+                }
+            }
+
+            // Check for logged events, i.e: `Transfer { ... }.log()`
+            Expr::MethodCall { target, path_seg, args, .. } if path_seg.name.as_str() == "log" && utils::fold_punctuated(&args.inner).is_empty() => {
+                self.add_log(target.as_ref());
+            }
+
+            Expr::MethodCall { target, path_seg, .. } => {
+                let abi_name = match target.as_ref() {
+                    // Check for external calls on abi casts, i.e: `abi(MyAbi, id).f()`
+                    Expr::AbiCast { args, .. } => Some(utils::path_type_to_string(&args.inner.name)),
+
+                    // Check for external calls on abi variables, i.e: `let x = abi(MyAbi, id); x.f()`
+                    Expr::Path(path) if path.suffix.is_empty() => {
+                        let Some(variable) = scope.borrow().get_variable(path.prefix.name.as_str(), false) else { return };
+                        let ty_name = utils::ty_to_string(&variable.borrow().ty);
+
+                        scope.borrow().find_abi(|x| x.borrow().name.as_str() == ty_name).map(|_| ty_name)
+                    }
+
+                    _ => None,
+                };
+
+                if let Some(abi_name) = abi_name {
+                    push_unique(&mut self.external_calls, format!("{abi_name}::{}", path_seg.name.as_str()));
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Adds the type of the logged value `expr` to the logs of the function
+    fn add_log(&mut self, expr: &Expr) {
+        let name = abi_diff::get_logged_ty_name(expr).unwrap_or_else(|| expr.span().as_str().to_string());
+        push_unique(&mut self.logs, name);
+    }
+}
+
+impl AstVisitor for EntryPointsPrinter {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        self.module_states.insert(context.path.into(), ModuleState::default());
+
+        // Get or create the package state
+        self.package_states.visit_module(project, context.path);
+
+        Ok(())
+    }
+
+    fn visit_use(&mut self, context: &UseContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Check the use tree for `std::logging::log`, i.e: `use std::logging::log as emit;`
+        if let Some(name) = utils::use_tree_to_name(&context.item_use.tree, "std::logging::log") {
+            module_state.log_names.push(name);
+        }
+
+        Ok(())
+    }
+
+    fn visit_abi(&mut self, context: &AbiContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        let Some(abi_defs) = context.item_abi.abi_defs_opt.as_ref() else { return Ok(()) };
+
+        let module_path = project.module_path(context.path);
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Default methods declared in the abi are externally callable on any contract implementing the abi
+        for item_fn in abi_defs.inner.iter() {
+            let fn_name = format!("{}::{}", context.item_abi.name.as_str(), item_fn.value.fn_signature.name.as_str());
+
+            let mut fn_state = FnState {
+                path: context.path.into(),
+                line: project.span_to_line(context.path, &item_fn.value.fn_signature.span())?,
+                entry_point_name: Some(fn_name.clone()),
+                attributes: get_fn_attributes(&item_fn.attribute_list),
+                guards: vec![],
+                assets: vec![],
+                external_calls: vec![],
+                logs: vec![],
+                fn_calls: vec![],
+                called_fns: vec![],
+            };
+
+            // Default method bodies are not visited, so check the expressions of their statements directly
+            let body = &item_fn.value.body.inner;
+
+            let exprs = body.statements.iter()
+                .filter_map(|statement| match statement {
+                    Statement::Let(statement_let) => Some(&statement_let.expr),
+                    Statement::Expr { expr, .. } => Some(expr),
+                    _ => None,
+                })
+                .chain(body.final_expr_opt.as_deref());
+
+            for expr in exprs {
+                utils::map_expr(expr, &mut |expr| fn_state.check_expr(expr, context.module, &module_path, None, module_state, &scope));
+            }
+
+            let package_state = self.package_states.get_mut(context.path);
+            package_state.fn_states.insert(call_graph::get_fn_node_name(&module_path, None, &fn_name), fn_state);
+        }
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only list externally callable functions, i.e: ABI implementations for `Contract` or the `main` function of a script or predicate
+        let entry_point_name = match (context.item_impl, context.item) {
+            (Some(item_impl), _) => match item_impl.trait_opt.as_ref() {
+                Some((path_type, _)) if item_impl.ty.span().as_str() == "Contract" => {
+                    Some(format!("{}::{}", path_type.span().as_str(), context.item_fn.fn_signature.name.as_str()))
+                }
+
+                _ => None,
+            },
+
+            (None, ItemKind::Fn(_)) if context.item_fn.fn_signature.name.as_str() == "main" => {
+                matches!(context.module.kind, ModuleKind::Script { .. } | ModuleKind::Predicate { .. }).then(|| "main".to_string())
+            }
+
+            _ => None,
+        };
+
+        let module_path = project.module_path(context.path);
+        let fn_name = call_graph::get_fn_node_name(&module_path, context.item_impl, context.item_fn.fn_signature.name.as_str());
+        let line = project.span_to_line(context.path, &context.item_fn.fn_signature.span())?;
+
+        // Get the package state
+        let package_state = self.package_states.get_mut(context.path);
+
+        // Create the function state, since internal functions can guard, move assets, call contracts or log on behalf of their callers
+        package_state.fn_states.entry(fn_name).or_insert_with(|| FnState {
+            path: context.path.into(),
+            line,
+            entry_point_name,
+            attributes: get_fn_attributes(context.fn_attributes),
+            guards: vec![],
+            assets: vec![],
+            external_calls: vec![],
+            logs: vec![],
+            fn_calls: vec![],
+            called_fns: vec![],
+        });
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Only check expressions in functions
+        let Some(item_fn) = context.item_fn else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get(context.path).unwrap();
+
+        // Get the function state
+        let module_path = project.module_path(context.path);
+        let fn_name = call_graph::get_fn_node_name(&module_path, context.item_impl, item_fn.fn_signature.name.as_str());
+        let package_state = self.package_states.get_mut(context.path);
+        let Some(fn_state) = package_state.fn_states.get_mut(&fn_name) else { return Ok(()) };
+
+        fn_state.check_expr(context.expr, context.module, &module_path, context.item_impl, module_state, &scope);
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Remove the module state
+        self.module_states.remove(context.path);
+
+        // Only print the package once all of its modules have been visited
+        let Some(mut package_state) = self.package_states.leave_module(context.path) else { return Ok(()) };
+
+        // Resolve the functions called by each function of the package, keeping guard functions which are declared elsewhere
+        let fn_names = package_state.fn_states.keys().cloned().collect::<Vec<_>>();
+
+        for fn_state in package_state.fn_states.values_mut() {
+            for fn_call in fn_state.fn_calls.iter() {
+                if let Some(called_fn) = fn_call.candidates.iter().find(|x| fn_names.contains(x)) {
+                    fn_state.called_fns.push(called_fn.clone());
+                } else if let Some(guard) = fn_call.guard.as_ref() {
+                    push_unique(&mut fn_state.guards, guard.clone());
+                }
+            }
+        }
+
+        // Propagate the guards, assets, external calls and logs of called functions to the functions calling them
+        call_graph::propagate_fn_states(
+            &mut package_state.fn_states,
+            |fn_state| fn_state.called_fns.clone(),
+            |fn_state, called_fn_state| {
+                let mut changed = false;
+
+                for (values, called_values) in [
+                    (&mut fn_state.guards, &called_fn_state.guards),
+                    (&mut fn_state.assets, &called_fn_state.assets),
+                    (&mut fn_state.external_calls, &called_fn_state.external_calls),
+                    (&mut fn_state.logs, &called_fn_state.logs),
+                ] {
+                    for value in called_values {
+                        if !values.contains(value) {
+                            values.push(value.clone());
+                            changed = true;
+                        }
+                    }
+                }
+
+                changed
+            },
+        );
+
+        let mut fn_states = package_state.fn_states.into_values()
+            .filter(|fn_state| fn_state.entry_point_name.is_some())
+            .collect::<Vec<_>>();
+
+        fn_states.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+
+        // Print a table of the entry points declared in each module of the package
+        let mut paths = fn_states.iter().map(|fn_state| fn_state.path.clone()).collect::<Vec<_>>();
+        paths.dedup();
+
+        for path in paths {
+            let rows = fn_states.iter()
+                .filter(|fn_state| fn_state.path == path)
+                .map(|fn_state| vec![
+                    fn_state.entry_point_name.clone().unwrap(),
+                    fn_state.attributes.join(", "),
+                    fn_state.guards.join(", "),
+                    fn_state.assets.join(", "),
+                    fn_state.external_calls.join(", "),
+                    fn_state.logs.join(", "),
+                ])
+                .collect();
+
+            project.summary.borrow_mut().add_table(
+                "entry_points",
+                Some(path.as_path()),
+                "Entry Points",
+                &["Function", "Attributes", "Guards", "Assets", "External Calls", "Logs"],
+                rows,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_entry_points() {
+        let summary = crate::tests::test_printer("entry_points", 5);
+
+        let get_cell = |function: &str, column: &str| {
+            crate::tests::get_cell(&summary, "Entry Points", "Function", function, column).unwrap()
+        };
+
+        assert_eq!(get_cell("Vault::deposit", "Attributes"), "storage(read, write), payable");
+        assert_eq!(get_cell("Vault::deposit", "Logs"), "Deposit");
+        assert_eq!(get_cell("Vault::withdraw", "Assets"), "transfer(AssetId::base())");
+        assert_eq!(get_cell("Vault::withdraw", "Logs"), "Withdrawal");
+        assert_eq!(get_cell("Vault::set_owner", "Guards"), "msg_sender().unwrap() == storage.owner.read()");
+        assert_eq!(get_cell("Vault::quote", "External Calls"), "Oracle::price");
+        assert_eq!(get_cell("Vault::oracle_price", "External Calls"), "Oracle::price");
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    error::Error,
+    project::Project,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ModuleContext},
+};
+use std::{cell::RefCell, rc::Rc};
+use sway_ast::{ItemImplItem, ItemKind};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct ItemSummaryPrinter;
+
+impl AstVisitor for ItemSummaryPrinter {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        let mut items: Vec<(Span, &str, String)> = vec![];
+
+        for item in context.module.items.iter() {
+            match &item.value {
+                ItemKind::Submodule(submodule) => {
+                    items.push((submodule.name.span(), "Submodule", submodule.name.as_str().to_string()));
+                }
+
+                ItemKind::Struct(item_struct) => {
+                    items.push((item_struct.name.span(), "Struct", item_struct.name.as_str().to_string()));
+                }
+
+                ItemKind::Enum(item_enum) => {
+                    items.push((item_enum.name.span(), "Enum", item_enum.name.as_str().to_string()));
+                }
+
+                ItemKind::Fn(item_fn) => {
+                    items.push((item_fn.fn_signature.name.span(), "Function", item_fn.fn_signature.name.as_str().to_string()));
+                }
+
+                ItemKind::Trait(item_trait) => {
+                    items.push((item_trait.name.span(), "Trait", item_trait.name.as_str().to_string()));
+                }
+
+                ItemKind::Impl(item_impl) => {
+                    let name = match item_impl.trait_opt.as_ref() {
+                        Some((path_type, _)) => format!("{} for {}", path_type.span().as_str(), item_impl.ty.span().as_str()),
+                        None => item_impl.ty.span().as_str().to_string(),
+                    };
+
+                    items.push((item_impl.span(), "Impl", name));
+
+                    // Add the functions declared in the impl, i.e: `Contract::transfer`
+                    for impl_item in item_impl.contents.inner.iter() {
+                        let ItemImplItem::Fn(item_fn) = &impl_item.value else { continue };
+
+                        items.push((
+                            item_fn.fn_signature.name.span(),
+                            "Function",
+                            format!("{}::{}", item_impl.ty.span().as_str(), item_fn.fn_signature.name.as_str()),
+                        ));
+                    }
+                }
+
+                ItemKind::Abi(item_abi) => {
+                    items.push((item_abi.name.span(), "Abi", item_abi.name.as_str().to_string()));
+                }
+
+                ItemKind::Const(item_const) => {
+                    items.push((item_const.name.span(), "Constant", item_const.name.as_str().to_string()));
+                }
+
+                ItemKind::Storage(item_storage) => {
+                    for field in utils::fold_punctuated(&item_storage.fields.inner) {
+                        items.push((field.value.name.span(), "Storage Field", field.value.name.as_str().to_string()));
+                    }
+                }
+
+                ItemKind::Configurable(item_configurable) => {
+                    for field in utils::fold_punctuated(&item_configurable.fields.inner) {
+                        items.push((field.value.name.span(), "Configurable", field.value.name.as_str().to_string()));
+                    }
+                }
+
+                ItemKind::TypeAlias(item_type_alias) => {
+                    items.push((item_type_alias.name.span(), "Type Alias", item_type_alias.name.as_str().to_string()));
+                }
+
+                ItemKind::Use(_) | ItemKind::Error(_, _) => {}
+            }
+        }
+
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        let mut rows = vec![];
+
+        for (span, kind, name) in items {
+            let line = project.span_to_line(context.path, &span)?;
+
+            rows.push(vec![
+                line.map(|line| line.to_string()).unwrap_or_default(),
+                kind.to_string(),
+                name,
+            ]);
+        }
+
+        project.summary.borrow_mut().add_table(
+            "item_summary",
+            Some(context.path),
+            "Items",
+            &["Line", "Kind", "Name"],
+            rows,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_item_summary() {
+        let summary = crate::tests::test_printer("item_summary", 11);
+
+        let get_kind = |name: &str| crate::tests::get_cell(&summary, "Items", "Name", name, "Kind");
+
+        assert_eq!(get_kind("Balance").as_deref(), Some("Type Alias"));
+        assert_eq!(get_kind("OWNER").as_deref(), Some("Configurable"));
+        assert_eq!(get_kind("balances").as_deref(), Some("Storage Field"));
+        assert_eq!(get_kind("Token for Contract").as_deref(), Some("Impl"));
+        assert_eq!(get_kind("Contract::balance_of").as_deref(), Some("Function"));
+        assert_eq!(crate::tests::get_cell(&summary, "Items", "Name", "is_owner", "Line").as_deref(), Some("32"));
+    }
+}
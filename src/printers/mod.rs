@@ -0,0 +1,18 @@
+mod code_metrics;
+mod entry_points;
+mod item_summary;
+mod storage_access;
+
+use crate::visitor::AstVisitor;
+
+use self::{code_metrics::*, entry_points::*, item_summary::*, storage_access::*};
+
+type PrinterConstructor = fn() -> Box<dyn AstVisitor>;
+type PrinterEntry = (&'static str, PrinterConstructor);
+
+pub const PRINTER_TYPES: &[PrinterEntry] = &[
+    ("code_metrics", || Box::<CodeMetricsPrinter>::default()),
+    ("entry_points", || Box::<EntryPointsPrinter>::default()),
+    ("item_summary", || Box::<ItemSummaryPrinter>::default()),
+    ("storage_access", || Box::<StorageAccessPrinter>::default()),
+];
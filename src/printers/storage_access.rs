@@ -0,0 +1,161 @@
+use crate::{
+    error::Error,
+    project::Project,
+    scope::AstScope,
+    utils,
+    visitor::{AstVisitor, ExprContext, FnContext, ModuleContext},
+};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+use sway_types::{Span, Spanned};
+
+#[derive(Default)]
+pub struct StorageAccessPrinter {
+    module_states: HashMap<PathBuf, ModuleState>,
+}
+
+#[derive(Default)]
+struct ModuleState {
+    fn_states: Vec<FnState>,
+    storage_names: Vec<String>,
+    storage_call_targets: Vec<Span>,
+}
+
+struct FnState {
+    fn_span: Span,
+    name: String,
+    storage_reads: Vec<String>,
+    storage_writes: Vec<String>,
+}
+
+impl FnState {
+    /// Describes how the function accesses the `storage_name` storage field, i.e: `R`, `W` or `RW`
+    fn access(&self, storage_name: &str) -> String {
+        let is_read = self.storage_reads.iter().any(|x| x == storage_name);
+        let is_written = self.storage_writes.iter().any(|x| x == storage_name);
+
+        match (is_read, is_written) {
+            (true, true) => "RW".into(),
+            (true, false) => "R".into(),
+            (false, true) => "W".into(),
+            (false, false) => String::new(),
+        }
+    }
+}
+
+impl AstVisitor for StorageAccessPrinter {
+    fn visit_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Create the module state
+        self.module_states.insert(
+            context.path.into(),
+            ModuleState {
+                storage_names: utils::collect_storage_fields(context.module).iter().map(|x| x.name.as_str().to_string()).collect(),
+                ..Default::default()
+            },
+        );
+
+        Ok(())
+    }
+
+    fn visit_fn(&mut self, context: &FnContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        let name = match context.item_impl.as_ref() {
+            Some(item_impl) => format!("{}::{}", item_impl.ty.span().as_str(), context.item_fn.fn_signature.name.as_str()),
+            None => context.item_fn.fn_signature.name.as_str().to_string(),
+        };
+
+        // Create the function state
+        module_state.fn_states.push(FnState {
+            fn_span: context.item_fn.fn_signature.span(),
+            name,
+            storage_reads: vec![],
+            storage_writes: vec![],
+        });
+
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, context: &ExprContext, _scope: Rc<RefCell<AstScope>>, _project: &mut Project) -> Result<(), Error> {
+        // Only check expressions in functions
+        let Some(item_fn) = context.item_fn else { return Ok(()) };
+
+        // Get the module state
+        let module_state = self.module_states.get_mut(context.path).unwrap();
+
+        // Only check storage method calls, i.e: `storage.balances.insert(k, v)`
+        let Some((storage_name, writes)) = utils::get_storage_access(context.expr, &mut module_state.storage_call_targets) else { return Ok(()) };
+
+        if !module_state.storage_names.contains(&storage_name) {
+            module_state.storage_names.push(storage_name.clone());
+        }
+
+        // Get the function state
+        let fn_span = item_fn.fn_signature.span();
+        let Some(fn_state) = module_state.fn_states.iter_mut().find(|x| x.fn_span == fn_span) else { return Ok(()) };
+
+        let storage_names = if writes {
+            &mut fn_state.storage_writes
+        } else {
+            &mut fn_state.storage_reads
+        };
+
+        if !storage_names.contains(&storage_name) {
+            storage_names.push(storage_name);
+        }
+
+        Ok(())
+    }
+
+    fn leave_module(&mut self, context: &ModuleContext, _scope: Rc<RefCell<AstScope>>, project: &mut Project) -> Result<(), Error> {
+        // Get the module state
+        let Some(module_state) = self.module_states.remove(context.path) else { return Ok(()) };
+
+        // Only print modules which declare or access storage
+        if module_state.storage_names.is_empty() {
+            return Ok(());
+        }
+
+        let mut columns = vec!["Function"];
+        columns.extend(module_state.storage_names.iter().map(String::as_str));
+
+        let rows = module_state.fn_states.iter()
+            .map(|fn_state| {
+                let mut row = vec![fn_state.name.clone()];
+                row.extend(module_state.storage_names.iter().map(|storage_name| fn_state.access(storage_name)));
+                row
+            })
+            .collect();
+
+        project.summary.borrow_mut().add_table(
+            "storage_access",
+            Some(context.path),
+            "Storage Access",
+            &columns,
+            rows,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_storage_access() {
+        let summary = crate::tests::test_printer("storage_access", 7);
+
+        let get_access = |function: &str, storage_name: &str| {
+            crate::tests::get_cell(&summary, "Storage Access", "Function", function, storage_name).unwrap()
+        };
+
+        assert_eq!(get_access("only_owner", "owner"), "R");
+        assert_eq!(get_access("Contract::deposit", "balances"), "RW");
+        assert_eq!(get_access("Contract::deposit", "holders"), "W");
+        assert_eq!(get_access("Contract::withdraw", "paused"), "R");
+        assert_eq!(get_access("Contract::pause", "owner"), "");
+        assert_eq!(get_access("Contract::pause", "paused"), "W");
+        assert_eq!(get_access("Contract::holder_count", "holders"), "R");
+        assert_eq!(get_access("Contract::remove_last_holder", "holders"), "W");
+    }
+}
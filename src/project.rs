@@ -1,4 +1,6 @@
-use crate::{detectors::*, error::Error, report::Report, scope::AstScope, visitor::*, Options};
+use crate::{
+    detectors::*, error::Error, printers::*, report::Report, scope::AstScope, summary::Summary, visitor::*, Options,
+};
 use std::{
     cell::RefCell,
     collections::HashMap,
@@ -36,22 +38,84 @@ pub struct Project<'a> {
     display_format: DisplayFormat,
     line_ranges: HashMap<PathBuf, Vec<(usize, usize)>>,
     modules: Rc<RefCell<HashMap<PathBuf, Module>>>,
-    detectors: Rc<RefCell<AstVisitorRecursive<'a>>>,
+    module_packages: HashMap<PathBuf, PathBuf>,
+    visitors: Rc<RefCell<AstVisitorRecursive<'a>>>,
+    printing: bool,
     pub report: Rc<RefCell<Report>>,
+    pub summary: Rc<RefCell<Summary>>,
     pub resolver: Rc<RefCell<AstResolver>>,
 }
 
+/// Per-package state which is kept until all of the modules of the package have been visited.
+pub struct PackageStates<T> {
+    module_packages: HashMap<PathBuf, PathBuf>,
+    package_states: HashMap<PathBuf, (Vec<PathBuf>, T)>,
+}
+
+impl<T> Default for PackageStates<T> {
+    fn default() -> Self {
+        Self {
+            module_packages: HashMap::new(),
+            package_states: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Default> PackageStates<T> {
+    /// Gets or creates the state of the package containing the module at `path`.
+    pub fn visit_module(&mut self, project: &Project, path: &Path) -> &mut T {
+        let package_root = project.package_root(path);
+
+        self.module_packages.insert(path.into(), package_root.clone());
+
+        let (_, package_state) = self.package_states.entry(package_root.clone()).or_insert_with(|| {
+            (project.package_module_paths(package_root.as_path()), T::default())
+        });
+
+        package_state
+    }
+
+    /// Gets the state of the package containing the module at `path`.
+    pub fn get_mut(&mut self, path: &Path) -> &mut T {
+        let package_root = self.module_packages.get(path).unwrap();
+        &mut self.package_states.get_mut(package_root).unwrap().1
+    }
+
+    /// Marks the module at `path` as visited, taking the state of its package once all of its modules have been visited.
+    pub fn leave_module(&mut self, path: &Path) -> Option<T> {
+        let package_root = self.module_packages.get(path).unwrap();
+        let (remaining_module_paths, _) = self.package_states.get_mut(package_root).unwrap();
+
+        remaining_module_paths.retain(|module_path| module_path != path);
+
+        if !remaining_module_paths.is_empty() {
+            return None;
+        }
+
+        self.package_states.remove(package_root).map(|(_, package_state)| package_state)
+    }
+}
+
 impl Display for Project<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.display_format {
-            DisplayFormat::Text => {
+        match (self.display_format, self.printing) {
+            (DisplayFormat::Text, false) => {
                 write!(f, "{}", self.report.borrow())?;
             }
 
-            DisplayFormat::Json => {
+            (DisplayFormat::Json, false) => {
                 let value = serde_json::to_value(self.report.borrow().clone()).unwrap();
                 write!(f, "{}", value)?;
             }
+
+            (DisplayFormat::Text, true) => {
+                write!(f, "{}", self.summary.borrow())?;
+            }
+
+            (DisplayFormat::Json, true) => {
+                let value = serde_json::to_value(self.summary.borrow().clone()).unwrap();
+                write!(f, "{}", value)?;
+            }
         }
 
         Ok(())
@@ -64,6 +128,7 @@ impl TryFrom<&Options> for Project<'_> {
     fn try_from(options: &Options) -> Result<Self, Self::Error> {
         let mut project = Project {
             display_format: options.display_format.unwrap_or_default(),
+            printing: !options.print.is_empty(),
             report: Rc::new(RefCell::new(Report {
                 sorting: options.sorting.unwrap_or_default(),
                 ..Default::default()
@@ -76,10 +141,13 @@ impl TryFrom<&Options> for Project<'_> {
                 // TODO
             }
     
-            fn parse_dir<P: AsRef<Path>>(project: &mut Project, path: P) -> Result<(), Error> {
+            fn parse_dir<P: AsRef<Path>>(project: &mut Project, path: P, package_root: &Path) -> Result<(), Error> {
                 for entry in path.as_ref().read_dir().map_err(|e| Error::Wrapped(Box::new(e)))? {
                     let Ok(entry) = entry else { continue };
                     let path = entry.path();
+
+                    // Files below a directory containing `Forc.toml` belong to its package
+                    let package_root = if path.join("Forc.toml").is_file() { path.as_path() } else { package_root };
     
                     let forc_toml_path = PathBuf::from(format!("{}Forc.toml", path.to_string_lossy()));
     
@@ -87,22 +155,22 @@ impl TryFrom<&Options> for Project<'_> {
                         let src_path = PathBuf::from(format!("{}src", path.to_string_lossy()));
                 
                         if src_path.is_dir() && src_path.exists() {
-                            parse_dir(project, src_path)?;
+                            parse_dir(project, src_path, package_root)?;
                             continue;
                         }    
                     }
             
                     if path.is_dir() {
-                        parse_dir(project, path)?;
+                        parse_dir(project, path.as_path(), package_root)?;
                     } else if path.is_file() && path.extension().map(|x| x == "sw").unwrap_or(false) {
-                        project.parse_file(path)?;
+                        project.parse_package_file(path.as_path(), package_root)?;
                     }
                 }
     
                 Ok(())
             }
     
-            parse_dir(&mut project, path)?;
+            parse_dir(&mut project, path, path)?;
         }
     
         for path in options.files.iter() {
@@ -118,9 +186,25 @@ impl TryFrom<&Options> for Project<'_> {
             }
         }
     
-        for &(detector_name, create_detector) in DETECTOR_TYPES {
-            if options.detectors.is_empty() || options.detectors.iter().any(|v| v == detector_name) {
-                project.detectors.borrow_mut().visitors.push(create_detector());
+        // Check if printers are valid and if not, return an error with the printer name that is not valid.
+        for printer in &options.print {
+            if !PRINTER_TYPES.iter().any(|(name, _)| printer == *name) {
+                return Err(Error::Wrapped(format!("Printer not found in printers collection : {printer}").into()));
+            }
+        }
+
+        // Printers are utilized instead of the detectors
+        if options.print.is_empty() {
+            for &(detector_name, create_detector) in DETECTOR_TYPES {
+                if options.detectors.is_empty() || options.detectors.iter().any(|v| v == detector_name) {
+                    project.visitors.borrow_mut().visitors.push(create_detector());
+                }
+            }
+        }
+
+        for &(printer_name, create_printer) in PRINTER_TYPES {
+            if options.print.iter().any(|v| v == printer_name) {
+                project.visitors.borrow_mut().visitors.push(create_printer());
             }
         }
     
@@ -131,6 +215,18 @@ impl TryFrom<&Options> for Project<'_> {
 impl Project<'_> {
     /// Attempts to parse the file from the supplied `path`.
     pub fn parse_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+        // Files outside of a directory containing `Forc.toml` all belong to the same package
+        let package_root = path.as_ref().ancestors()
+            .skip(1)
+            .find(|dir| dir.join("Forc.toml").is_file())
+            .map(PathBuf::from)
+            .unwrap_or_default();
+
+        self.parse_package_file(path, package_root.as_path())
+    }
+
+    /// Attempts to parse the file from the supplied `path` as a module of the package at `package_root`.
+    fn parse_package_file<P: AsRef<Path>>(&mut self, path: P, package_root: &Path) -> Result<(), Error> {
         let path = PathBuf::from(path.as_ref().to_string_lossy().replace("\\\\", "\\").replace("//", "/"));
         let source = std::fs::read_to_string(path.clone()).map_err(|e| Error::Wrapped(Box::new(e)))?;
         
@@ -140,7 +236,8 @@ impl Project<'_> {
         let source = Arc::from(source.as_str());
         let module = sway_parse::parse_file(&handler, source, None).map_err(|_| Error::ParseFailed(path.clone()))?;
 
-        self.modules.borrow_mut().insert(path, module.value);
+        self.modules.borrow_mut().insert(path.clone(), module.value);
+        self.module_packages.insert(path, package_root.into());
 
         Ok(())
     }
@@ -180,10 +277,57 @@ impl Project<'_> {
         Err(Error::LineNotFound(path.into(), offset))
     }
 
+    /// Gets the modules parsed from all of the parsed files, keyed by the path of each file.
+    pub fn modules(&self) -> Rc<RefCell<HashMap<PathBuf, Module>>> {
+        self.modules.clone()
+    }
+
+    /// Gets the paths of all of the parsed files.
+    pub fn module_paths(&self) -> Vec<PathBuf> {
+        self.modules.borrow().keys().cloned().collect()
+    }
+
+    /// Gets the root directory of the package containing the parsed file at `path`.
+    pub fn package_root(&self, path: &Path) -> PathBuf {
+        self.module_packages.get(path).cloned().unwrap_or_default()
+    }
+
+    /// Gets the paths of all of the parsed files in the package at `package_root`.
+    pub fn package_module_paths(&self, package_root: &Path) -> Vec<PathBuf> {
+        self.module_packages.iter()
+            .filter(|(_, root)| root.as_path() == package_root)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    /// Gets the path of the module parsed from the file at `path` within its package, i.e: `["utils", "math"]` for `src/utils/math.sw`
+    pub fn module_path(&self, path: &Path) -> Vec<String> {
+        let package_root = self.package_root(path);
+        let Ok(relative_path) = path.strip_prefix(package_root.join("src")) else { return vec![] };
+
+        let result = relative_path.with_extension("")
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+
+        // The root module of a package is `main.sw`, or `lib.sw` when the package has no `main.sw`
+        let is_root = match result.as_slice() {
+            [name] if name == "main" => true,
+            [name] if name == "lib" => !self.modules.borrow().contains_key(&path.with_file_name("main.sw")),
+            _ => false,
+        };
+
+        if is_root {
+            return vec![];
+        }
+
+        result
+    }
+
     /// Attempts to analyze all of the parsed files.
     pub fn analyze_modules(&mut self) -> Result<(), Error> {
         let modules = self.modules.clone();
-        let detectors = self.detectors.clone();
+        let visitors = self.visitors.clone();
 
         let mut module_paths = modules.borrow().keys().cloned().collect::<Vec<_>>();
         module_paths.sort();
@@ -205,8 +349,8 @@ impl Project<'_> {
 
             let scope = Rc::new(RefCell::new(AstScope::default()));
 
-            detectors.borrow_mut().visit_module(&context, scope.clone(), self)?;
-            detectors.borrow_mut().leave_module(&context, scope.clone(), self)?;
+            visitors.borrow_mut().visit_module(&context, scope.clone(), self)?;
+            visitors.borrow_mut().leave_module(&context, scope.clone(), self)?;
         }
 
         Ok(())
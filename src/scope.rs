@@ -20,6 +20,7 @@ pub struct AstVariable {
     pub kind: AstVariableKind,
     pub name: String,
     pub ty: Ty,
+    pub span: Span,
 }
 
 #[derive(Debug, Default)]
@@ -306,6 +307,7 @@ impl AstScope {
             kind,
             name: name.to_string(),
             ty: self.expand_ty(project, ty, &[]),
+            span: name.span(),
         })));
     }
 
@@ -330,6 +332,27 @@ impl AstScope {
         None
     }
 
+    /// Finds all variables with the supplied name in the current scope and its parents, ordered from innermost to outermost.
+    pub fn get_variables(&self, name: &str, is_storage: bool) -> Vec<Rc<RefCell<AstVariable>>> {
+        let mut result = vec![];
+
+        for variable in self.variables.iter().rev() {
+            if (variable.borrow().kind == AstVariableKind::Storage) != is_storage {
+                continue;
+            }
+
+            if variable.borrow().name == name {
+                result.push(variable.clone());
+            }
+        }
+
+        if let Some(parent) = self.parent.as_ref() {
+            result.extend(parent.borrow().get_variables(name, is_storage));
+        }
+
+        result
+    }
+
     /// Gets an iterator over the all of the function signatures in the current scope. This does not include parent scopes.
     #[inline]
     pub fn fn_signatures(&self) -> impl Iterator<Item = &Rc<RefCell<FnSignature>>> {
@@ -0,0 +1,172 @@
+use crate::{error::Error, project::Project, report::Severity, utils};
+use std::path::PathBuf;
+use sway_ast::{Expr, ItemKind, Literal};
+use sway_types::Spanned;
+
+/// A field declared in a `storage` block.
+#[derive(Clone, Debug)]
+pub struct StorageFieldLayout {
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub ty: String,
+    pub key: Option<String>,
+}
+
+impl StorageFieldLayout {
+    /// Gets the name of the field including its namespace, i.e: `my_namespace::x`
+    pub fn qualified_name(&self) -> String {
+        match self.namespace.as_ref() {
+            Some(namespace) => format!("{namespace}::{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Attempts to get the fields of the `storage` blocks declared in all of the parsed files of `project`.
+pub fn storage_layout(project: &mut Project) -> Result<Vec<StorageFieldLayout>, Error> {
+    let modules = project.modules();
+    let modules = modules.borrow();
+
+    let mut module_paths = modules.keys().cloned().collect::<Vec<_>>();
+    module_paths.sort();
+
+    let mut result = vec![];
+
+    for path in module_paths {
+        let module = modules.get(&path).unwrap();
+
+        for item in module.items.iter() {
+            let ItemKind::Storage(item_storage) = &item.value else { continue };
+
+            // Get the namespace of the storage block, i.e: `#[namespace(my_namespace)]`
+            let namespace = utils::get_storage_namespace(&item.attribute_list);
+
+            for field in utils::fold_punctuated(&item_storage.fields.inner) {
+                result.push(StorageFieldLayout {
+                    path: path.clone(),
+                    line: project.span_to_line(&path, &field.value.name.span())?,
+                    namespace: namespace.clone(),
+                    name: field.value.name.as_str().to_string(),
+                    ty: utils::ty_to_string(&field.value.ty),
+                    key: field.value.key_expr.as_ref().map(|key_expr| match key_expr {
+                        // Normalize integer keys so that `0x01` and its zero-padded form are the same key
+                        Expr::Literal(Literal::Int(value)) => format!("0x{:064x}", value.parsed),
+                        _ => key_expr.span().as_str().to_string(),
+                    }),
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Finds the field of `new_fields` which corresponds to `old_field`, matching by qualified name first and then by name,
+/// so that a field which was moved to a different namespace is still compared against its previous declaration.
+fn find_new_field<'a>(
+    old_field: &StorageFieldLayout,
+    old_fields: &[StorageFieldLayout],
+    new_fields: &'a [StorageFieldLayout],
+) -> Option<&'a StorageFieldLayout> {
+    new_fields.iter()
+        .find(|x| x.qualified_name() == old_field.qualified_name())
+        .or_else(|| new_fields.iter().find(|x| {
+            x.name == old_field.name && !old_fields.iter().any(|y| y.qualified_name() == x.qualified_name())
+        }))
+}
+
+/// Compares the storage layout of the `old` project against the storage layout of the `new` project,
+/// adding an entry to the report of the `new` project for each change which is an upgrade hazard.
+pub fn diff_storage_layouts(old: &mut Project, new: &mut Project) -> Result<(), Error> {
+    let old_fields = storage_layout(old)?;
+    let new_fields = storage_layout(new)?;
+
+    let mut report = new.report.borrow_mut();
+
+    // Check for removed fields, which leave their previous values orphaned in storage
+    for old_field in old_fields.iter() {
+        if find_new_field(old_field, &old_fields, &new_fields).is_some() {
+            continue;
+        }
+
+        report.add_entry(
+            old_field.path.clone(),
+            old_field.line,
+            Severity::Medium,
+            format!(
+                "The `{}` storage field was removed. Consider keeping the field in order to preserve its existing value.",
+                old_field.qualified_name(),
+            ),
+        );
+    }
+
+    // Get the fields which are declared in both versions, in the order they are declared in the old version
+    let common_fields = old_fields.iter()
+        .filter_map(|old_field| find_new_field(old_field, &old_fields, &new_fields).map(|new_field| (old_field, new_field)))
+        .collect::<Vec<_>>();
+
+    let mut new_order = common_fields.iter().map(|(_, new_field)| new_field.qualified_name()).collect::<Vec<_>>();
+    new_order.sort_by_key(|name| new_fields.iter().position(|x| x.qualified_name() == *name));
+
+    for (i, (old_field, new_field)) in common_fields.iter().enumerate() {
+        // Check for fields which are stored at a different explicit key or in a different namespace
+        if old_field.key != new_field.key || old_field.namespace != new_field.namespace {
+            report.add_entry(
+                new_field.path.clone(),
+                new_field.line,
+                Severity::High,
+                format!(
+                    "The `{}` storage field was moved from {} to {}. Consider keeping the field at its previous storage key.",
+                    old_field.qualified_name(),
+                    describe_storage_location(old_field),
+                    describe_storage_location(new_field),
+                ),
+            );
+        }
+
+        // Check for fields whose values will be decoded using a different type
+        if old_field.ty != new_field.ty {
+            report.add_entry(
+                new_field.path.clone(),
+                new_field.line,
+                Severity::High,
+                format!(
+                    "The `{}` storage field was changed from `{}` to `{}`. Consider adding a new field instead of changing the type of an existing field.",
+                    new_field.qualified_name(),
+                    old_field.ty,
+                    new_field.ty,
+                ),
+            );
+        }
+
+        // Check for fields which were reordered relative to the other fields of the old version
+        if new_order[i] != new_field.qualified_name() {
+            report.add_entry(
+                new_field.path.clone(),
+                new_field.line,
+                Severity::Low,
+                format!(
+                    "The `{}` storage field was reordered. Consider declaring new fields after existing fields in order to keep the storage layout easy to compare.",
+                    new_field.qualified_name(),
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Describes where the value of a storage field is stored, i.e: "the `0x...` key in the `x` namespace"
+fn describe_storage_location(field: &StorageFieldLayout) -> String {
+    let key = match field.key.as_ref() {
+        Some(key) => format!("the `{key}` key"),
+        None => "the default key".to_string(),
+    };
+
+    match field.namespace.as_ref() {
+        Some(namespace) => format!("{key} in the `{namespace}` namespace"),
+        None => key,
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, path::PathBuf};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Table {
+    pub printer: String,
+    pub path: Option<PathBuf>,
+    pub title: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.path.as_ref() {
+            Some(path) => writeln!(f, "{} ({}):", self.title, path.to_string_lossy())?,
+            None => writeln!(f, "{}:", self.title)?,
+        }
+
+        // Get the width of each column, which is the width of its longest cell
+        let mut widths = self.columns.iter().map(|column| column.chars().count()).collect::<Vec<_>>();
+
+        for row in self.rows.iter() {
+            for (i, cell) in row.iter().enumerate() {
+                if i < widths.len() {
+                    widths[i] = widths[i].max(cell.chars().count());
+                } else {
+                    widths.push(cell.chars().count());
+                }
+            }
+        }
+
+        let format_row = |cells: &[String]| {
+            cells.iter()
+                .enumerate()
+                .map(|(i, cell)| format!("{cell:width$}", width = widths[i]))
+                .collect::<Vec<_>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        };
+
+        writeln!(f, "\t{}", format_row(&self.columns))?;
+        writeln!(f, "\t{}", widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>().join("-|-"))?;
+
+        for row in self.rows.iter() {
+            writeln!(f, "\t{}", format_row(row))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Summary {
+    pub tables: Vec<Table>,
+}
+
+impl Summary {
+    pub fn add_table<P: Into<PathBuf>, S: Into<String>>(
+        &mut self,
+        printer: &str,
+        path: Option<P>,
+        title: S,
+        columns: &[&str],
+        rows: Vec<Vec<String>>,
+    ) {
+        self.tables.push(Table {
+            printer: printer.into(),
+            path: path.map(Into::into),
+            title: title.into(),
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            rows,
+        });
+
+        self.tables.sort_by(|a, b| (&a.printer, &a.path).cmp(&(&b.printer, &b.path)));
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, table) in self.tables.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{table}")?;
+        }
+
+        Ok(())
+    }
+}
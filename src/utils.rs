@@ -1,3 +1,5 @@
+use crate::scope::{AstScope, AstVariableKind};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use sway_ast::{assignable::ElementAccess, attribute::{Annotated, Attribute}, ty::{TyArrayDescriptor, TyTupleDescriptor}, *};
 use sway_types::{BaseIdent, Span, Spanned};
 
@@ -245,6 +247,12 @@ pub fn fold_path_idents(path: &PathExpr) -> Vec<BaseIdent> {
     result
 }
 
+/// Gets the source text of an integer literal, i.e: `1000000` or `0x1234...`
+pub fn get_int_literal_str(expr: &Expr) -> Option<&str> {
+    let Expr::Literal(Literal::Int(i)) = expr else { return None };
+    Some(i.span.as_str())
+}
+
 pub fn fold_expr_idents(expr: &Expr) -> Vec<BaseIdent> {
     let mut result = vec![];
 
@@ -669,6 +677,15 @@ fn check_attribute(
     results.iter().all(|x| *x)
 }
 
+/// Attempts to get the namespace of a `storage` block, i.e: `#[namespace(my_namespace)]`
+pub fn get_storage_namespace(attribute_decls: &[AttributeDecl]) -> Option<String> {
+    attribute_decls.iter()
+        .flat_map(|attribute_decl| fold_punctuated(&attribute_decl.attribute.inner))
+        .find(|attribute| attribute.name.as_str() == "namespace")
+        .and_then(|attribute| attribute.args.as_ref())
+        .and_then(|args| fold_punctuated(&args.inner).first().map(|arg| arg.name.as_str().to_string()))
+}
+
 pub fn statement_to_variable_binding_ident(statement: &Statement) -> Option<BaseIdent> {
     let Statement::Let(StatementLet {
         pattern,
@@ -1123,7 +1140,7 @@ fn is_storage_key_write_fn(s: &str) -> bool {
 }
 
 fn is_storage_map_write_fn(s: &str) -> bool {
-    matches!(s, "insert" | "remove")
+    matches!(s, "insert" | "remove" | "try_insert")
 }
 
 fn is_storage_string_write_fn(s: &str) -> bool {
@@ -1131,7 +1148,41 @@ fn is_storage_string_write_fn(s: &str) -> bool {
 }
 
 fn is_storage_vec_write_fn(s: &str) -> bool {
-    matches!(s, "push" | "pop" | "remove" | "swap_remove" | "set" | "insert" | "clear" | "swap" | "reverse" | "fill" | "resize")
+    matches!(s, "push" | "pop" | "remove" | "swap_remove" | "set" | "insert" | "clear" | "swap" | "reverse" | "fill" | "resize" | "store_vec")
+}
+
+/// Checks if `s` is the name of a method which writes to a storage type, i.e: `write`, `insert` or `push`
+pub fn is_storage_write_fn(s: &str) -> bool {
+    is_storage_bytes_write_fn(s)
+        || is_storage_key_write_fn(s)
+        || is_storage_map_write_fn(s)
+        || is_storage_string_write_fn(s)
+        || is_storage_vec_write_fn(s)
+}
+
+/// Checks if `expr` is the outermost call of a storage access chain, which contains all of its methods, i.e: `storage.x.get(k).write(v)`,
+/// returning the name of the accessed storage field and whether any method of the chain writes to storage.
+///
+/// The targets of checked calls are stored in `storage_call_targets` so that the inner calls of the chain are skipped when they are visited.
+pub fn get_storage_access(expr: &Expr, storage_call_targets: &mut Vec<Span>) -> Option<(String, bool)> {
+    let Expr::MethodCall { target, .. } = expr else { return None };
+
+    let storage_idents = fold_expr_idents(expr);
+
+    if storage_idents.len() < 3 || storage_idents[0].as_str() != "storage" {
+        return None;
+    }
+
+    storage_call_targets.push(target.span());
+
+    if storage_call_targets.contains(&expr.span()) {
+        return None;
+    }
+
+    // Any method in the chain can write to storage, i.e: `storage.x.pop().unwrap()`
+    let writes = storage_idents[2..].iter().any(|ident| is_storage_write_fn(ident.as_str()));
+
+    Some((storage_idents[1].as_str().to_string(), writes))
 }
 
 pub fn storage_write_statement_to_storage_variable_ident(statement: &Statement) -> Option<BaseIdent> {
@@ -1148,13 +1199,8 @@ pub fn storage_write_statement_to_storage_variable_ident(statement: &Statement)
         return None;
     }
 
-    match storage_idents.last().unwrap().as_str() {
-        s if is_storage_bytes_write_fn(s) => {}
-        s if is_storage_key_write_fn(s) => {}
-        s if is_storage_map_write_fn(s) => {}
-        s if is_storage_string_write_fn(s) => {}
-        s if is_storage_vec_write_fn(s) => {}
-        _ => return None,
+    if !is_storage_write_fn(storage_idents.last().unwrap().as_str()) {
+        return None;
     }
 
     Some(storage_idents[1].clone())
@@ -1181,13 +1227,8 @@ pub fn statement_to_storage_write_idents(statement: &Statement) -> Option<(BaseI
         return None;
     }
 
-    match storage_idents.last().unwrap().as_str() {
-        s if is_storage_bytes_write_fn(s) => {}
-        s if is_storage_key_write_fn(s) => {}
-        s if is_storage_map_write_fn(s) => {}
-        s if is_storage_string_write_fn(s) => {}
-        s if is_storage_vec_write_fn(s) => {}
-        _ => return None,
+    if !is_storage_write_fn(storage_idents.last().unwrap().as_str()) {
+        return None;
     }
 
     let args = fold_punctuated(&args.inner);
@@ -1255,6 +1296,17 @@ pub fn get_if_revert_condition(expr: &Expr) -> Option<&IfCondition> {
     Some(condition)
 }
 
+/// Checks if `expr` calls the function at `path` or any of the `names` it was imported as, i.e: `std::block::timestamp()`
+pub fn is_fn_call(expr: &Expr, path: &str, names: &[String]) -> bool {
+    let Expr::FuncApp { func, .. } = expr else { return false };
+    let Expr::Path(func_path) = func.as_ref() else { return false };
+
+    let func_path = func_path.span();
+    let func_path = func_path.as_str();
+
+    func_path == path || names.iter().any(|name| func_path == name)
+}
+
 pub fn pattern_to_constructor_suffix_and_value(name: &str, pattern: &Pattern) -> Option<(BaseIdent, BaseIdent)> {
     let Pattern::Constructor { path, args } = pattern else { return None };
     if path.prefix.name.as_str() != name { return None; }
@@ -1758,3 +1810,115 @@ pub fn create_ident_ty(name: &str) -> Ty {
         suffix: vec![],
     })
 }
+
+/// An instruction which executes the code of another contract.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ContractCodeInstruction {
+    /// Loads the code of the contract into the current context, i.e: `ldc target_id offset len`
+    Ldc,
+    /// Calls the contract in its own context, i.e: `call target amount asset_id gas`
+    Call,
+}
+
+/// Checks if `instruction` executes the code of another contract.
+pub fn get_contract_code_instruction(instruction: &Instruction) -> Option<ContractCodeInstruction> {
+    match instruction.op_code_ident().as_str() {
+        "ldc" => Some(ContractCodeInstruction::Ldc),
+        "call" => Some(ContractCodeInstruction::Call),
+        _ => None,
+    }
+}
+
+/// Where a value originates from, as traced through local variable bindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueSource {
+    Parameter,
+    StorageRead,
+    MsgSender,
+    MsgAmount,
+    ExternalCall,
+    Arithmetic,
+}
+
+impl ValueSource {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ValueSource::Parameter => "a function parameter",
+            ValueSource::StorageRead => "a storage read",
+            ValueSource::MsgSender => "the result of `msg_sender()`",
+            ValueSource::MsgAmount => "`msg_amount()`",
+            ValueSource::ExternalCall => "the result of an external call",
+            ValueSource::Arithmetic => "an arithmetic result",
+        }
+    }
+}
+
+pub struct VarSource {
+    pub name: String,
+    pub source: Option<ValueSource>,
+    pub is_abi: bool,
+}
+
+/// Keeps track of the sources of local variables declared in each block of a function.
+#[derive(Default)]
+pub struct VarSources {
+    block_vars: HashMap<Span, Vec<VarSource>>,
+}
+
+impl VarSources {
+    pub fn add_var(&mut self, block_span: &Span, name: &str, source: Option<ValueSource>, is_abi: bool) {
+        self.block_vars.entry(block_span.clone()).or_default().push(VarSource {
+            name: name.to_string(),
+            source,
+            is_abi,
+        });
+    }
+
+    pub fn find_var(&self, name: &str, blocks: &[Span]) -> Option<&VarSource> {
+        for block_span in blocks.iter().rev() {
+            let Some(vars) = self.block_vars.get(block_span) else { continue };
+
+            if let Some(var) = vars.iter().rev().find(|v| v.name == name) {
+                return Some(var);
+            }
+        }
+
+        None
+    }
+
+    /// Classifies the source of `expr`. The `f` callback is checked first in order to supply detector-specific sources.
+    pub fn classify_expr<F>(&self, expr: &Expr, blocks: &[Span], scope: Rc<RefCell<AstScope>>, f: &F) -> Option<ValueSource>
+    where
+        F: Fn(&Expr) -> Option<ValueSource>,
+    {
+        if let Some(source) = f(expr) {
+            return Some(source);
+        }
+
+        match expr {
+            Expr::Parens(parens) => self.classify_expr(&parens.inner, blocks, scope, f),
+
+            Expr::MethodCall { .. } => {
+                // Check if the method call is a storage access, i.e: `storage.x.read()`
+                let is_storage_read = fold_expr_idents(expr).first().map(|ident| ident.as_str() == "storage").unwrap_or(false);
+                is_storage_read.then_some(ValueSource::StorageRead)
+            }
+
+            Expr::Path(_) => {
+                let name = expr.span().as_str().to_string();
+
+                // Check if the path is a local variable bound to a known source
+                if let Some(var) = self.find_var(name.as_str(), blocks) {
+                    return var.source;
+                }
+
+                // Check if the path is a function parameter
+                let variable = scope.borrow().get_variable(name.as_str(), false)?;
+                let is_parameter = variable.borrow().kind == AstVariableKind::Parameter;
+                is_parameter.then_some(ValueSource::Parameter)
+            }
+
+            _ => None,
+        }
+    }
+}
@@ -997,7 +997,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
             for arg in args {
                 crate::utils::map_pattern_and_ty(&arg.pattern, &arg.ty, &mut |pattern, ty| {
                     match pattern {
-                        Pattern::Var { name, .. } => {
+                        Pattern::AmbiguousSingleIdent(name) | Pattern::Var { name, .. } => {
                             scope.borrow_mut().add_variable(project, AstVariableKind::Parameter, name, ty);
                         }
 
@@ -1137,7 +1137,7 @@ impl AstVisitor for AstVisitorRecursive<'_> {
                 .unwrap_or_else(|| scope.borrow().get_expr_ty(&context.statement_let.expr, project)),
             &mut |pattern, ty| {
                 match pattern {
-                    Pattern::Var { name, .. } => {
+                    Pattern::AmbiguousSingleIdent(name) | Pattern::Var { name, .. } => {
                         scope.borrow_mut().add_variable(project, AstVariableKind::Local, name, ty);
                     }
 
@@ -0,0 +1,75 @@
+contract;
+
+use std::{bytes::Bytes, low_level_call::{call_with_function_selector, CallParams}};
+
+abi Token {
+    fn mint(amount: u64);
+}
+
+const TOKEN_ID = 0x79fa8779bed2f36c3581d01c79df8da45eee09fac1fd76a5a656e16326317ef0;
+
+storage {
+    allowed: StorageMap<ContractId, bool> = StorageMap {},
+}
+
+abi TestArbitraryExternalCall {
+    fn test_arbitrary_external_call_1(target: ContractId);
+    fn test_arbitrary_external_call_2(target: b256);
+    fn test_arbitrary_external_call_3(target: ContractId, function_selector: Bytes, calldata: Bytes);
+    #[storage(read)]
+    fn test_arbitrary_external_call_4(target: ContractId);
+    fn test_arbitrary_external_call_5();
+    fn test_arbitrary_external_call_6(target: ContractId);
+    fn test_arbitrary_external_call_7(target: b256);
+}
+
+impl TestArbitraryExternalCall for Contract {
+    fn test_arbitrary_external_call_1(target: ContractId) {
+        // Report entry should be created:
+        // L28: The `Contract::test_arbitrary_external_call_1` function performs an external call to a caller-supplied contract: `abi(Token, target.bits())`. Consider validating `target` against an allow-list.
+        abi(Token, target.bits()).mint(1);
+    }
+
+    fn test_arbitrary_external_call_2(target: b256) {
+        let id = target;
+
+        // Report entry should be created:
+        // L36: The `Contract::test_arbitrary_external_call_2` function performs an external call to a caller-supplied contract: `abi(Token, id)`. Consider validating `id` against an allow-list.
+        let token = abi(Token, id);
+        token.mint(1);
+    }
+
+    fn test_arbitrary_external_call_3(target: ContractId, function_selector: Bytes, calldata: Bytes) {
+        // Report entry should be created:
+        // L43: The `Contract::test_arbitrary_external_call_3` function performs an external call to a caller-supplied contract: `call_with_function_selector(target, function_selector, calldata, CallParams { coins: 0, asset_id: AssetId::base(), gas: 10_000 })`. Consider validating `target` against an allow-list.
+        call_with_function_selector(target, function_selector, calldata, CallParams { coins: 0, asset_id: AssetId::base(), gas: 10_000 });
+    }
+
+    #[storage(read)]
+    fn test_arbitrary_external_call_4(target: ContractId) {
+        require(storage.allowed.get(target).try_read().unwrap_or(false), "target not allowed");
+
+        // Report entry should not be created
+        abi(Token, target.bits()).mint(1);
+    }
+
+    fn test_arbitrary_external_call_5() {
+        // Report entry should not be created
+        abi(Token, TOKEN_ID).mint(1);
+    }
+
+    fn test_arbitrary_external_call_6(target: ContractId) {
+        require(target != ContractId::zero(), "target is zero");
+
+        // Report entry should be created:
+        // L66: The `Contract::test_arbitrary_external_call_6` function performs an external call to a caller-supplied contract: `abi(Token, target.bits())`. Consider validating `target` against an allow-list.
+        abi(Token, target.bits()).mint(1);
+    }
+
+    fn test_arbitrary_external_call_7(target: b256) {
+        require(target == TOKEN_ID, "target not allowed");
+
+        // Report entry should not be created
+        abi(Token, target).mint(1);
+    }
+}
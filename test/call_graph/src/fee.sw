@@ -0,0 +1,17 @@
+library;
+
+pub struct Fee<T> {
+    pub amount: T,
+}
+
+impl<T> Fee<T> {
+    pub fn new(amount: T) -> Self {
+        Self { amount }
+    }
+}
+
+impl Fee<u64> {
+    pub fn apply(self, value: u64) -> u64 {
+        value - self.amount
+    }
+}
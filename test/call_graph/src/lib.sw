@@ -0,0 +1,5 @@
+library;
+
+pub fn get_sender() -> Identity {
+    msg_sender().unwrap()
+}
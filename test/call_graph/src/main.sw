@@ -0,0 +1,56 @@
+contract;
+
+mod fee;
+mod lib;
+
+use fee::Fee;
+use ownership::only_owner;
+
+abi Oracle {
+    fn price() -> u64;
+}
+
+abi Vault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    fn quote(oracle_id: ContractId) -> u64;
+
+    #[storage(read)]
+    fn pause();
+}
+
+storage {
+    balances: StorageMap<Identity, u64> = StorageMap {},
+}
+
+fn get_sender() -> Identity {
+    lib::get_sender()
+}
+
+#[storage(read)]
+fn balance_of(owner: Identity) -> u64 {
+    storage.balances.get(owner).try_read().unwrap_or(0)
+}
+
+impl Vault for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        let sender = get_sender();
+        let balance = balance_of(sender);
+        storage.balances.insert(sender, balance + msg_amount());
+    }
+
+    fn quote(oracle_id: ContractId) -> u64 {
+        let oracle = abi(Oracle, oracle_id.into());
+        let fee: Fee<u64> = Fee::new(1);
+        fee.apply(oracle.price())
+    }
+
+    #[storage(read)]
+    fn pause() {
+        only_owner();
+    }
+}
@@ -0,0 +1,71 @@
+contract;
+
+abi Oracle {
+    fn price() -> u64;
+}
+
+abi Vault {
+    #[storage(read, write)]
+    fn deposit(amount: u64);
+
+    #[storage(read)]
+    fn fee_for(amount: u64, oracle_id: ContractId) -> u64;
+}
+
+enum Tier {
+    Basic: (),
+    Silver: (),
+    Gold: (),
+}
+
+storage {
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    total: u64 = 0,
+}
+
+fn get_tier(amount: u64) -> Tier {
+    if amount < 100 {
+        Tier::Basic
+    } else if amount < 1000 {
+        Tier::Silver
+    } else {
+        Tier::Gold
+    }
+}
+
+fn sum(values: Vec<u64>) -> u64 {
+    let mut result = 0;
+    let mut i = 0;
+
+    while i < values.len() {
+        if values.get(i).is_some() && result < 1000 {
+            result += values.get(i).unwrap();
+        }
+
+        i += 1;
+    }
+
+    result
+}
+
+impl Vault for Contract {
+    #[storage(read, write)]
+    fn deposit(amount: u64) {
+        let sender = msg_sender().unwrap();
+        let balance = storage.balances.get(sender).try_read().unwrap_or(0);
+        storage.balances.insert(sender, balance + amount);
+        storage.total.write(storage.total.read() + amount);
+    }
+
+    #[storage(read)]
+    fn fee_for(amount: u64, oracle_id: ContractId) -> u64 {
+        let oracle = abi(Oracle, oracle_id.into());
+        let rate = match get_tier(amount) {
+            Tier::Basic => 3,
+            Tier::Silver => 2,
+            Tier::Gold => 1,
+        };
+
+        (amount * rate * oracle.price()) / storage.total.read()
+    }
+}
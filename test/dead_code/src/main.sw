@@ -0,0 +1,86 @@
+contract;
+
+mod utils;
+
+use ::utils::quadruple;
+
+configurable {
+    // Report entry should not be created
+    LIMIT: u64 = 100,
+
+    // Report entry should be created:
+    // L13: The `UNUSED_LIMIT` configurable is never read. Consider removing any dead code.
+    UNUSED_LIMIT: u64 = 200,
+}
+
+// Report entry should not be created
+const FEE: u64 = 5;
+
+// Report entry should be created:
+// L21: The `UNUSED_FEE` constant is never used. Consider removing any dead code.
+const UNUSED_FEE: u64 = 10;
+
+// Report entry should not be created
+struct Order {
+    amount: u64,
+}
+
+// Report entry should be created:
+// L30: The `Unused` struct is never used. Consider removing any dead code.
+struct Unused {
+    value: u64,
+}
+
+enum State {
+    // Report entry should not be created
+    Active: (),
+
+    // Report entry should be created:
+    // L40: The `State::Paused` enum variant is never constructed. Consider removing any dead code.
+    Paused: (),
+}
+
+storage {
+    // Report entry should not be created
+    total: u64 = 0,
+
+    // Report entry should be created:
+    // L49: The `legacy` storage field is never read. Consider removing any dead code.
+    legacy: u64 = 0,
+}
+
+fn is_active(state: State) -> bool {
+    match state {
+        State::Active => true,
+        State::Paused => false,
+    }
+}
+
+// Report entry should be created:
+// L61: The `bonus` function is never called. Consider removing any dead code.
+fn bonus(amount: u64) -> u64 {
+    amount / 10
+}
+
+abi TestDeadCode {
+    #[storage(read, write)]
+    fn test_dead_code(amount: u64) -> u64;
+}
+
+impl TestDeadCode for Contract {
+    #[storage(read, write)]
+    fn test_dead_code(amount: u64) -> u64 {
+        let order = Order { amount: quadruple(amount) + FEE };
+
+        require(order.amount <= LIMIT, "limit exceeded");
+        require(is_active(State::Active), "inactive");
+
+        storage.legacy.write(order.amount);
+
+        // A local variable binding does not use the function with the same name
+        let bonus = order.amount / 100;
+
+        storage.total.write(storage.total.read() + order.amount + bonus);
+        storage.total.read()
+    }
+}
@@ -0,0 +1,16 @@
+library;
+
+// Report entry should be created:
+// L5: The `unused_helper` function is never called. Consider removing any dead code.
+fn unused_helper() -> u64 {
+    1
+}
+
+// Report entry should not be created
+fn double(value: u64) -> u64 {
+    value * 2
+}
+
+pub fn quadruple(value: u64) -> u64 {
+    double(double(value))
+}
@@ -0,0 +1,66 @@
+contract;
+
+struct Deposit {
+    sender: Identity,
+    amount: u64,
+}
+
+abi VaultV2 {
+    #[storage(write)]
+    fn deposit(amount: u64);
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64, recipient: Identity) -> bool;
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64;
+
+    #[storage(read)]
+    fn total_deposits() -> u64;
+
+    #[storage(read)]
+    fn is_paused() -> bool;
+}
+
+storage {
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    total_deposits: u64 = 0,
+    paused: bool = false,
+}
+
+impl VaultV2 for Contract {
+    #[storage(write)]
+    fn deposit(amount: u64) {
+        let sender = msg_sender().unwrap();
+        storage.balances.insert(sender, amount);
+        storage.total_deposits.write(amount);
+        Deposit { sender, amount }.log();
+    }
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64, recipient: Identity) -> bool {
+        let sender = msg_sender().unwrap();
+        let balance = storage.balances.get(sender).try_read().unwrap_or(0);
+        if balance < amount {
+            return false;
+        }
+        storage.balances.insert(sender, balance - amount);
+        transfer(recipient, AssetId::base(), amount);
+        true
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64 {
+        storage.balances.get(owner).try_read().unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn total_deposits() -> u64 {
+        storage.total_deposits.read()
+    }
+
+    #[storage(read)]
+    fn is_paused() -> bool {
+        storage.paused.read()
+    }
+}
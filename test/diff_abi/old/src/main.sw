@@ -0,0 +1,75 @@
+contract;
+
+use std::logging::log as emit;
+
+struct Deposit {
+    sender: Identity,
+    amount: u64,
+}
+
+struct Withdrawal {
+    recipient: Identity,
+    amount: u64,
+}
+
+abi Vault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64, recipient: Identity);
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64;
+
+    #[storage(read)]
+    fn total_deposits() -> u64;
+
+    fn version() -> u64;
+} {
+    fn max_deposit() -> u64 {
+        1000
+    }
+}
+
+storage {
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    total_deposits: u64 = 0,
+}
+
+impl Vault for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        let sender = msg_sender().unwrap();
+        let amount = msg_amount();
+        storage.balances.insert(sender, storage.balances.get(sender).try_read().unwrap_or(0) + amount);
+        storage.total_deposits.write(storage.total_deposits.read() + amount);
+        log(Deposit { sender, amount });
+    }
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64, recipient: Identity) {
+        let sender = msg_sender().unwrap();
+        let balance = storage.balances.get(sender).try_read().unwrap_or(0);
+        require(balance >= amount, "Insufficient balance");
+        storage.balances.insert(sender, balance - amount);
+        transfer(recipient, AssetId::base(), amount);
+        emit(Withdrawal { recipient, amount });
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64 {
+        storage.balances.get(owner).try_read().unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn total_deposits() -> u64 {
+        storage.total_deposits.read()
+    }
+
+    fn version() -> u64 {
+        1
+    }
+}
@@ -0,0 +1,10 @@
+contract;
+
+storage {
+    balance: u64 = 0,
+    owner: Identity = Identity::Address(Address::zero()),
+    paused: u8 = 0,
+    fee in 0x0000000000000000000000000000000000000000000000000000000000000002: u64 = 0,
+    rate in 0x0000000000000000000000000000000000000000000000000000000000000003: u64 = 0,
+    added: u64 = 0,
+}
@@ -0,0 +1,10 @@
+contract;
+
+storage {
+    owner: Identity = Identity::Address(Address::zero()),
+    balance: u64 = 0,
+    paused: bool = false,
+    fee in 0x0000000000000000000000000000000000000000000000000000000000000001: u64 = 0,
+    rate in 0x03: u64 = 0,
+    legacy: u64 = 0,
+}
@@ -0,0 +1,7 @@
+contract;
+
+#[namespace(vault)]
+storage {
+    owner: Identity = Identity::Address(Address::zero()),
+    balance: u64 = 0,
+}
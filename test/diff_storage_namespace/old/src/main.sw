@@ -0,0 +1,6 @@
+contract;
+
+storage {
+    owner: Identity = Identity::Address(Address::zero()),
+    balance: u64 = 0,
+}
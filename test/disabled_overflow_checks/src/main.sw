@@ -0,0 +1,84 @@
+contract;
+
+use std::flags::{disable_panic_on_overflow, enable_panic_on_overflow, set_flags};
+
+abi TestDisabledOverflowChecks {
+    fn test_disabled_overflow_checks_1(a: u64, b: u64) -> u64;
+    fn test_disabled_overflow_checks_2(a: u64, b: u64) -> u64;
+    fn test_disabled_overflow_checks_3(a: u64, b: u64) -> u64;
+    fn test_disabled_overflow_checks_4(a: u64, b: u64) -> u64;
+    fn test_disabled_overflow_checks_5(a: u64, b: u64) -> u64;
+    fn test_disabled_overflow_checks_6(a: u64, b: u64) -> u64;
+}
+
+impl TestDisabledOverflowChecks for Contract {
+    fn test_disabled_overflow_checks_1(a: u64, b: u64) -> u64 {
+        let prior = disable_panic_on_overflow();
+
+        // Report entry should be created:
+        // L20: The `Contract::test_disabled_overflow_checks_1` function performs arithmetic while overflow panics are disabled: `a + b`. Consider checking the result for overflow manually.
+        let result = a + b;
+
+        set_flags(prior);
+        result
+    }
+
+    fn test_disabled_overflow_checks_2(a: u64, b: u64) -> u64 {
+        // Report entry should be created:
+        // L29: The `Contract::test_disabled_overflow_checks_2` function disables overflow panics without restoring them before returning: `disable_panic_on_overflow()`. Consider calling `enable_panic_on_overflow` or `set_flags` with the prior flags.
+        let _ = disable_panic_on_overflow();
+
+        // Report entry should be created:
+        // L33: The `Contract::test_disabled_overflow_checks_2` function performs arithmetic while overflow panics are disabled: `a - b`. Consider checking the result for overflow manually.
+        a - b
+    }
+
+    fn test_disabled_overflow_checks_3(a: u64, b: u64) -> u64 {
+        let _ = disable_panic_on_overflow();
+
+        if a == 0 {
+            // Report entry should be created:
+            // L42: The `Contract::test_disabled_overflow_checks_3` function returns while overflow panics are disabled: `return 0`. Consider restoring them before returning.
+            return 0;
+        }
+
+        // Report entry should be created:
+        // L47: The `Contract::test_disabled_overflow_checks_3` function performs arithmetic while overflow panics are disabled: `a * b`. Consider checking the result for overflow manually.
+        let result = a * b;
+
+        enable_panic_on_overflow();
+        result
+    }
+
+    fn test_disabled_overflow_checks_4(a: u64, b: u64) -> u64 {
+        // Report entry should not be created
+        a + b
+    }
+
+    fn test_disabled_overflow_checks_5(a: u64, b: u64) -> u64 {
+        let prior = disable_panic_on_overflow();
+        set_flags(prior);
+
+        // Report entry should not be created
+        a + b
+    }
+
+    fn test_disabled_overflow_checks_6(a: u64, b: u64) -> u64 {
+        let prior = disable_panic_on_overflow();
+
+        if a > b {
+            set_flags(prior);
+        } else {
+            // Report entry should be created:
+            // L74: The `Contract::test_disabled_overflow_checks_6` function performs arithmetic while overflow panics are disabled: `b - a`. Consider checking the result for overflow manually.
+            let _ = b - a;
+        }
+
+        // Report entry should be created:
+        // L79: The `Contract::test_disabled_overflow_checks_6` function performs arithmetic while overflow panics are disabled: `a + b`. Consider checking the result for overflow manually.
+        let result = a + b;
+
+        set_flags(prior);
+        result
+    }
+}
@@ -0,0 +1,87 @@
+contract;
+
+use std::{auth::caller_address, b512::B512, ecr::ec_recover, inputs::input_coin_owner};
+
+abi Oracle {
+    fn price() -> Option<u64>;
+    fn update(price: u64) -> bool;
+    fn poke();
+}
+
+const ORACLE_ID = 0x79fa8779bed2f36c3581d01c79df8da45eee09fac1fd76a5a656e16326317ef0;
+
+storage {
+    counter: u64 = 0,
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    queue: StorageVec<u64> = StorageVec {},
+}
+
+abi TestDiscardedReturnValue {
+    fn test_discarded_return_value_1();
+    fn test_discarded_return_value_2();
+    #[storage(read)]
+    fn test_discarded_return_value_3();
+    fn test_discarded_return_value_4() -> u64;
+    #[storage(read, write)]
+    fn test_discarded_return_value_5(account: Identity);
+    fn test_discarded_return_value_6(signature: B512, msg_hash: b256);
+}
+
+impl TestDiscardedReturnValue for Contract {
+    fn test_discarded_return_value_1() {
+        // Report entry should be created:
+        // L34: The `Contract::test_discarded_return_value_1` function discards the `Option` value returned by `abi(Oracle, ORACLE_ID).price()`. Consider handling the returned value.
+        abi(Oracle, ORACLE_ID).price();
+    }
+
+    fn test_discarded_return_value_2() {
+        let oracle = abi(Oracle, ORACLE_ID);
+
+        // Report entry should be created:
+        // L42: The `Contract::test_discarded_return_value_2` function discards the `bool` value returned by `oracle.update(100)`. Consider handling the returned value.
+        oracle.update(100);
+
+        // Report entry should not be created
+        oracle.poke();
+    }
+
+    #[storage(read)]
+    fn test_discarded_return_value_3() {
+        // Report entry should be created:
+        // L52: The `Contract::test_discarded_return_value_3` function discards the `Option` value returned by `storage.counter.try_read()`. Consider handling the returned value.
+        storage.counter.try_read();
+    }
+
+    fn test_discarded_return_value_4() -> u64 {
+        // Report entry should not be created
+        let price = abi(Oracle, ORACLE_ID).price();
+        price.unwrap_or(0)
+    }
+
+    #[storage(read, write)]
+    fn test_discarded_return_value_5(account: Identity) {
+        // Report entry should not be created
+        storage.balances.remove(account);
+
+        // Report entry should not be created
+        storage.queue.pop();
+    }
+
+    fn test_discarded_return_value_6(signature: B512, msg_hash: b256) {
+        // Report entry should be created:
+        // L73: The `Contract::test_discarded_return_value_6` function discards the `Result` value returned by `caller_address()`. Consider handling the returned value.
+        caller_address();
+
+        // Report entry should be created:
+        // L77: The `Contract::test_discarded_return_value_6` function discards the `Result` value returned by `ec_recover(signature, msg_hash)`. Consider handling the returned value.
+        ec_recover(signature, msg_hash);
+
+        // Report entry should be created:
+        // L81: The `Contract::test_discarded_return_value_6` function discards the `Result` value returned by `std::ecr::ec_recover_address(signature, msg_hash)`. Consider handling the returned value.
+        std::ecr::ec_recover_address(signature, msg_hash);
+
+        // Report entry should be created:
+        // L85: The `Contract::test_discarded_return_value_6` function discards the `Option` value returned by `input_coin_owner(0)`. Consider handling the returned value.
+        input_coin_owner(0);
+    }
+}
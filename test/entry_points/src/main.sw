@@ -0,0 +1,96 @@
+contract;
+
+use std::logging::log as emit;
+
+abi Oracle {
+    fn price() -> u64;
+}
+
+abi Vault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64);
+
+    #[storage(read, write)]
+    fn set_owner(new_owner: Identity);
+
+    fn quote(oracle_id: ContractId) -> u64;
+} {
+    fn oracle_price(oracle_id: ContractId) -> u64 {
+        abi(Oracle, oracle_id.into()).price()
+    }
+}
+
+struct Deposit {
+    sender: Identity,
+    amount: u64,
+}
+
+struct Withdrawal {
+    recipient: Identity,
+    amount: u64,
+}
+
+struct OwnerChanged {
+    new_owner: Identity,
+}
+
+storage {
+    owner: Identity = Identity::Address(Address::zero()),
+    balances: StorageMap<Identity, u64> = StorageMap {},
+}
+
+#[storage(read)]
+fn only_owner() {
+    require(msg_sender().unwrap() == storage.owner.read(), "Not owner");
+}
+
+fn pay_out(recipient: Identity, amount: u64) {
+    transfer(recipient, AssetId::base(), amount);
+    Withdrawal { recipient, amount }.log();
+}
+
+struct Fee {
+    amount: u64,
+}
+
+impl Fee {
+    fn zero() -> Self {
+        Self { amount: 0 }
+    }
+}
+
+impl Vault for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        let sender = msg_sender().unwrap();
+        let amount = msg_amount();
+        storage.balances.insert(sender, storage.balances.get(sender).try_read().unwrap_or(0) + amount);
+        emit(Deposit { sender, amount });
+    }
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64) {
+        let sender = msg_sender().unwrap();
+        let balance = storage.balances.get(sender).try_read().unwrap_or(0);
+        require(balance >= amount, "Insufficient balance");
+        storage.balances.insert(sender, balance - amount);
+        pay_out(sender, amount);
+    }
+
+    #[storage(read, write)]
+    fn set_owner(new_owner: Identity) {
+        only_owner();
+        storage.owner.write(new_owner);
+        log(OwnerChanged { new_owner });
+    }
+
+    fn quote(oracle_id: ContractId) -> u64 {
+        let oracle = abi(Oracle, oracle_id.into());
+        oracle.price() + Fee::zero().amount
+    }
+}
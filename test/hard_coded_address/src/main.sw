@@ -0,0 +1,59 @@
+contract;
+
+const TREASURY: b256 = 0x8900c5bec4ca97d4febf9ceb4754a60d782abbf3cd815836c1872116f203f861;
+
+configurable {
+    // Report entry should not be created
+    OWNER: Address = Address::from(0x9ae5b658754e096e4d681c548daf46354495a437cc61492599e33fc64dcdc30c),
+}
+
+// Report entry should be created:
+// L12: The `ADMIN` constant contains a hard-coded address: `Identity::Address(Address::from(0x1d3a8f5ba6a6e9b2ac3b0b5a2f7e0b3e4b4c8f1d2a7e6c5b4a39281706f5e4d3))`. Consider moving it to a `configurable` block.
+const ADMIN: Identity = Identity::Address(Address::from(0x1d3a8f5ba6a6e9b2ac3b0b5a2f7e0b3e4b4c8f1d2a7e6c5b4a39281706f5e4d3));
+
+abi TestHardCodedAddress {
+    fn test_hard_coded_address_1() -> bool;
+    fn test_hard_coded_address_2() -> ContractId;
+    fn test_hard_coded_address_3() -> AssetId;
+    fn test_hard_coded_address_4() -> Address;
+    fn test_hard_coded_address_5() -> Address;
+    fn test_hard_coded_address_6() -> ContractId;
+}
+
+impl TestHardCodedAddress for Contract {
+    fn test_hard_coded_address_1() -> bool {
+        // Report entry should be created:
+        // L26: The `Contract::test_hard_coded_address_1` function contains a hard-coded address: `Identity::Address(Address::from(0x5c4b0a2e9f8d7c6b5a4938271605f4e3d2c1b0a99887766554433221100ffeed))`. Consider moving it to a `configurable` block.
+        msg_sender().unwrap() == Identity::Address(Address::from(0x5c4b0a2e9f8d7c6b5a4938271605f4e3d2c1b0a99887766554433221100ffeed))
+    }
+
+    fn test_hard_coded_address_2() -> ContractId {
+        // Report entry should be created:
+        // L32: The `Contract::test_hard_coded_address_2` function contains a hard-coded address: `ContractId::from(TREASURY)`. Consider moving it to a `configurable` block.
+        ContractId::from(TREASURY)
+    }
+
+    fn test_hard_coded_address_3() -> AssetId {
+        // Report entry should be created:
+        // L38: The `Contract::test_hard_coded_address_3` function contains a hard-coded address: `AssetId::from(0xf8f8b6283d7fa5b672b530cbb84fcccb4ff8dc40f8176ef4544ddb1f1952ad07)`. Consider moving it to a `configurable` block.
+        AssetId::from(0xf8f8b6283d7fa5b672b530cbb84fcccb4ff8dc40f8176ef4544ddb1f1952ad07)
+    }
+
+    fn test_hard_coded_address_4() -> Address {
+        // Report entry should not be created
+        OWNER
+    }
+
+    fn test_hard_coded_address_5() -> Address {
+        // Report entry should not be created
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000)
+    }
+
+    fn test_hard_coded_address_6() -> ContractId {
+        // Report entry should be created:
+        // L55: The `Contract::test_hard_coded_address_6` function contains a hard-coded address: `ContractId::from(VAULT)`. Consider moving it to a `configurable` block.
+        ContractId::from(VAULT)
+    }
+}
+
+const VAULT: b256 = 0x3e8a1c7f0b5d9e2a4c6f8b1d3e5a7c9f0b2d4e6a8c1f3b5d7e9a0c2e4f6b8d1a;
@@ -0,0 +1,41 @@
+contract;
+
+use std::hash::Hash;
+
+type Balance = u64;
+
+const MAX_SUPPLY: u64 = 1_000_000;
+
+configurable {
+    OWNER: Identity = Identity::Address(Address::zero()),
+}
+
+struct Transfer {
+    recipient: Identity,
+    amount: u64,
+}
+
+enum Error {
+    InsufficientBalance: (),
+}
+
+abi Token {
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> Balance;
+}
+
+storage {
+    balances: StorageMap<Identity, Balance> = StorageMap {},
+    total_supply: u64 = 0,
+}
+
+fn is_owner(identity: Identity) -> bool {
+    identity == OWNER
+}
+
+impl Token for Contract {
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> Balance {
+        storage.balances.get(owner).try_read().unwrap_or(0)
+    }
+}
@@ -0,0 +1,60 @@
+contract;
+
+abi TestNarrowingConversion {
+    fn test_narrowing_conversion_1(a: u256) -> u64;
+    fn test_narrowing_conversion_2(a: u64, b: u64) -> Option<u32>;
+    fn test_narrowing_conversion_3(a: u64) -> u32;
+    fn test_narrowing_conversion_4(a: b256) -> u64;
+    fn test_narrowing_conversion_5(a: u32) -> u64;
+    fn test_narrowing_conversion_6() -> u64;
+    fn test_narrowing_conversion_7(a: b256) -> u64;
+}
+
+impl TestNarrowingConversion for Contract {
+    fn test_narrowing_conversion_1(a: u256) -> u64 {
+        // Report entry should be created:
+        // L16: The `Contract::test_narrowing_conversion_1` function contains a narrowing conversion from `u256` to `u64` on a user-controlled value: `a.try_into().unwrap()`. Consider checking the value's bounds before converting.
+        let b: u64 = a.try_into().unwrap();
+        b
+    }
+
+    fn test_narrowing_conversion_2(a: u64, b: u64) -> Option<u32> {
+        let c = a * b;
+
+        // Report entry should be created:
+        // L25: The `Contract::test_narrowing_conversion_2` function contains a narrowing conversion from `u64` to `u32` on an arithmetic result: `c.as_u32()`. Consider checking the value's bounds before converting.
+        c.as_u32()
+    }
+
+    fn test_narrowing_conversion_3(a: u64) -> u32 {
+        // Report entry should be created:
+        // L31: The `Contract::test_narrowing_conversion_3` function contains a narrowing conversion from `u64` to `u32` on a user-controlled value: `u32::try_from(a).unwrap()`. Consider checking the value's bounds before converting.
+        u32::try_from(a).unwrap()
+    }
+
+    fn test_narrowing_conversion_4(a: b256) -> u64 {
+        // Report entry should be created:
+        // L37: The `Contract::test_narrowing_conversion_4` function contains a narrowing conversion from `b256` to a smaller type on a user-controlled value: `a.try_into().unwrap()`. Consider checking the value's bounds before converting.
+        a.try_into().unwrap()
+    }
+
+    fn test_narrowing_conversion_5(a: u32) -> u64 {
+        // Report entry should not be created
+        let b: u64 = a.try_into().unwrap();
+        b
+    }
+
+    fn test_narrowing_conversion_6() -> u64 {
+        let a: u256 = 5;
+
+        // Report entry should not be created
+        let b: u64 = a.try_into().unwrap();
+        b
+    }
+
+    fn test_narrowing_conversion_7(a: b256) -> u64 {
+        // Report entry should be created:
+        // L57: The `Contract::test_narrowing_conversion_7` function contains a narrowing conversion from `b256` to `u64` on a user-controlled value: `u64::from_be_bytes(a.to_be_bytes())`. Consider checking the value's bounds before converting.
+        u64::from_be_bytes(a.to_be_bytes())
+    }
+}
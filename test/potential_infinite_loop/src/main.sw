@@ -177,5 +177,16 @@ impl TestPotentialInfiniteLoops for Contract {
         // Report entry should be created:
         // L179: The `Contract::potential_infinite_loop` function contains a potentially infinite loop: `while z { ... }`. Consider adding a `break` statement.
         while z {}
+
+        let v: Vec<u64> = Vec::new();
+
+        // Report entry should be created:
+        // L185: The `Contract::potential_infinite_loop` function contains a potentially infinite loop: `while true { ... }`. Consider adding a `break` statement.
+        while true {
+            // A `break` in the body of a `for` loop only exits the `for` loop
+            for x in v.iter() {
+                break;
+            }
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,39 @@
+contract;
+
+use std::execution::run_external;
+
+abi SRC14 {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId);
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId>;
+}
+
+#[namespace(proxy)]
+storage {
+    // Report entry should not be created
+    target: Option<ContractId> = None,
+
+    owner: Option<Identity> = None,
+}
+
+impl SRC14 for Contract {
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId) {
+        require(storage.owner.read() == Some(msg_sender().unwrap()), "not owner");
+        storage.target.write(Some(new_target));
+    }
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId> {
+        storage.target.read()
+    }
+}
+
+// Report entry should not be created
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    run_external(storage.target.read().unwrap())
+}
@@ -0,0 +1,45 @@
+contract;
+
+use std::execution::run_external;
+
+abi SRC14 {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId);
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId>;
+}
+
+storage {
+    // Report entry should be created:
+    // L15: The `target` storage field is used by the SRC-14 proxy without a dedicated storage slot, which can collide with the storage of the proxy target. Consider declaring it with an explicit storage key, i.e: `target in 0x...`, or in a `#[namespace(...)]` storage block.
+    target: Option<ContractId> = None,
+
+    // Report entry should not be created
+    owner in 0xbb79927b15d9259ea316f2ecb2297d6cc8851888a98278c0a2e03e1a091ea754: Option<Identity> = None,
+}
+
+impl SRC14 for Contract {
+    // Report entry should be created:
+    // L25: The `Contract::set_proxy_target` function can change the proxy target without access restriction. Consider checking against `msg_sender()` in order to limit access to the proxy owner.
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId) {
+        storage.target.write(Some(new_target));
+    }
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId> {
+        storage.target.read()
+    }
+}
+
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    let target = storage.target.read().unwrap();
+
+    asm(target: target, amount: 0, asset_id: AssetId::base(), gas: 10000) {
+        // Report entry should be created:
+        // L43: The `fallback` function forwards calls using the `CALL` instruction: `call target amount asset_id gas`. Consider using `run_external` in order to execute the proxy target's code in the context of the proxy.
+        call target amount asset_id gas;
+    }
+}
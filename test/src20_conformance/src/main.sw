@@ -0,0 +1,144 @@
+contract;
+
+use std::{asset::{burn, mint_to}, hash::Hash, string::String, storage::storage_string::*};
+
+abi SRC20 {
+    #[storage(read)]
+    fn total_assets() -> u64;
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64>;
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String>;
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String>;
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8>;
+}
+
+abi SRC3 {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: Option<SubId>, amount: u64);
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64);
+}
+
+abi Admin {
+    #[storage(write)]
+    fn set_name(name: String);
+    #[storage(write)]
+    fn set_name_logged(name: String);
+    #[storage(write)]
+    fn set_decimals(decimals: u64);
+    #[storage(write)]
+    fn set_decimals_nested(decimals: u64);
+}
+
+storage {
+    total_supply: u64 = 0,
+    name: StorageString = StorageString {},
+    decimals: u64 = 9,
+}
+
+// Report entry should be created:
+// L45: The `SRC20` implementation for `Contract` is missing the `fn symbol(asset: AssetId) -> Option<String>` function. Consider implementing the standard completely.
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        1
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        if asset == AssetId::default() {
+            Some(storage.total_supply.read())
+        } else {
+            None
+        }
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        if asset == AssetId::default() {
+            storage.name.read_slice()
+        } else {
+            None
+        }
+    }
+
+    // Report entry should be created:
+    // L72: The `Contract::decimals` function does not match the `SRC20` standard: `fn decimals(asset: AssetId) -> Option<u8>`. Consider updating the function signature.
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u64> {
+        if asset == AssetId::default() {
+            Some(storage.decimals.read())
+        } else {
+            None
+        }
+    }
+}
+
+#[storage(read, write)]
+fn update_total_supply(amount: u64) {
+    let supply = storage.total_supply.read() + amount;
+    storage.total_supply.write(supply);
+    log(TotalSupplyEvent { asset: AssetId::default(), supply, sender: msg_sender().unwrap() });
+}
+
+impl SRC3 for Contract {
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: Option<SubId>, amount: u64) {
+        update_total_supply(amount);
+        mint_to(recipient, sub_id.unwrap_or(SubId::zero()), amount);
+    }
+
+    // Report entry should be created:
+    // L101: The `Contract::burn` function does not update the `total_supply` storage field reported by the `Contract::total_supply` function. Consider updating the total supply.
+    // L101: The `Contract::burn` function does not log a `TotalSupplyEvent`. Consider logging the standard event when the total supply changes.
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64) {
+        burn(sub_id, amount);
+    }
+}
+
+impl Admin for Contract {
+    // Report entry should be created:
+    // L110: The `Contract::set_name` function writes to the `name` storage field without logging a `SetNameEvent`. Consider logging the standard event when the metadata changes.
+    #[storage(write)]
+    fn set_name(name: String) {
+        storage.name.write_slice(name);
+    }
+
+    // Report entry should not be created
+    #[storage(write)]
+    fn set_name_logged(name: String) {
+        storage.name.write_slice(name);
+        SetNameEvent::new(AssetId::default(), Some(name), msg_sender().unwrap()).log();
+    }
+
+    // Report entry should be created:
+    // L124: The `Contract::set_decimals` function writes to the `decimals` storage field without logging a `SetDecimalsEvent`. Consider logging the standard event when the metadata changes.
+    #[storage(write)]
+    fn set_decimals(decimals: u64) {
+        storage.decimals.write(decimals);
+    }
+
+    // Report entry should be created:
+    // L131: The `Contract::set_decimals_nested` function writes to the `decimals` storage field without logging a `SetDecimalsEvent`. Consider logging the standard event when the metadata changes.
+    #[storage(write)]
+    fn set_decimals_nested(decimals: u64) {
+        apply_decimals(decimals);
+    }
+}
+
+#[storage(write)]
+fn apply_decimals(decimals: u64) {
+    store_decimals(decimals);
+}
+
+#[storage(write)]
+fn store_decimals(decimals: u64) {
+    storage.decimals.write(decimals);
+}
@@ -0,0 +1,93 @@
+contract;
+
+use ownership::{initialize_ownership, only_owner, transfer_ownership};
+use src5::State;
+
+configurable {
+    INITIAL_OWNER: Identity = Identity::Address(Address::zero()),
+}
+
+abi TestSrc5Ownership {
+    #[storage(read, write)]
+    fn test_src5_ownership_1(new_owner: Identity);
+    #[storage(read, write)]
+    fn test_src5_ownership_2();
+    #[storage(read, write)]
+    fn test_src5_ownership_3(new_owner: Identity);
+    #[storage(read, write)]
+    fn test_src5_ownership_4(new_owner: Identity);
+    #[storage(read, write)]
+    fn test_src5_ownership_5(new_owner: Identity);
+    #[storage(read, write)]
+    fn test_src5_ownership_6();
+    #[storage(read, write)]
+    fn test_src5_ownership_7();
+    #[storage(read, write)]
+    fn test_src5_ownership_8(new_owner: Identity, state: State);
+}
+
+storage {
+    owner: State = State::Uninitialized,
+}
+
+impl TestSrc5Ownership for Contract {
+    #[storage(read, write)]
+    fn test_src5_ownership_1(new_owner: Identity) {
+        // Report entry should be created:
+        // L38: The `Contract::test_src5_ownership_1` function initializes ownership without access restriction, which allows anyone to claim ownership before the intended owner: `initialize_ownership(new_owner)`. Consider calling `only_owner()` or checking against `msg_sender()` in order to limit access.
+        initialize_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn test_src5_ownership_2() {
+        // Report entry should not be created
+        require(msg_sender().unwrap() == INITIAL_OWNER, "Unauthorized");
+        initialize_ownership(INITIAL_OWNER);
+    }
+
+    #[storage(read, write)]
+    fn test_src5_ownership_3(new_owner: Identity) {
+        // Report entry should be created:
+        // L52: The `Contract::test_src5_ownership_3` function initializes ownership without checking that it is uninitialized, which allows ownership to be re-initialized by anyone: `storage.owner.write(State::Initialized(new_owner))`. Consider calling `only_owner()` or checking against `msg_sender()` in order to limit access.
+        storage.owner.write(State::Initialized(new_owner));
+    }
+
+    #[storage(read, write)]
+    fn test_src5_ownership_4(new_owner: Identity) {
+        require(storage.owner.read() == State::Uninitialized, "AlreadyInitialized");
+
+        // Report entry should be created:
+        // L61: The `Contract::test_src5_ownership_4` function initializes ownership without access restriction, which allows anyone to claim ownership before the intended owner: `storage.owner.write(State::Initialized(new_owner))`. Consider calling `only_owner()` or checking against `msg_sender()` in order to limit access.
+        storage.owner.write(State::Initialized(new_owner));
+    }
+
+    #[storage(read, write)]
+    fn test_src5_ownership_5(new_owner: Identity) {
+        // Report entry should be created:
+        // L68: The `Contract::test_src5_ownership_5` function changes ownership without access restriction: `transfer_ownership(new_owner)`. Consider calling `only_owner()` or checking against `msg_sender()` in order to limit access.
+        transfer_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn test_src5_ownership_6() {
+        // Report entry should not be created
+        only_owner();
+        storage.owner.write(State::Revoked);
+    }
+
+    #[storage(read, write)]
+    fn test_src5_ownership_7() {
+        // Report entry should be created:
+        // L82: The `Contract::test_src5_ownership_7` function changes ownership without access restriction: `storage.owner.write(State::Revoked)`. Consider calling `only_owner()` or checking against `msg_sender()` in order to limit access.
+        storage.owner.write(State::Revoked);
+    }
+
+    #[storage(read, write)]
+    fn test_src5_ownership_8(new_owner: Identity, state: State) {
+        require(state == State::Uninitialized, "AlreadyInitialized");
+
+        // Report entry should be created:
+        // L91: The `Contract::test_src5_ownership_8` function initializes ownership without checking that it is uninitialized, which allows ownership to be re-initialized by anyone: `storage.owner.write(State::Initialized(new_owner))`. Consider calling `only_owner()` or checking against `msg_sender()` in order to limit access.
+        storage.owner.write(State::Initialized(new_owner));
+    }
+}
@@ -0,0 +1,17 @@
+contract;
+
+use ownership::only_owner;
+
+abi TestSrc5OwnershipUninitialized {
+    #[storage(read)]
+    fn test_src5_ownership_uninitialized();
+}
+
+impl TestSrc5OwnershipUninitialized for Contract {
+    #[storage(read)]
+    fn test_src5_ownership_uninitialized() {
+        // Report entry should be created:
+        // L15: The `Contract::test_src5_ownership_uninitialized` function restricts access to the SRC-5 owner, but ownership is never initialized: `only_owner()`. Consider calling `initialize_ownership` in a protected initializer.
+        only_owner();
+    }
+}
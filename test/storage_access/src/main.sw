@@ -0,0 +1,84 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+abi Vault {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64);
+
+    #[storage(read, write)]
+    fn pause();
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64;
+
+    #[storage(read)]
+    fn holder_count() -> u64;
+
+    #[storage(read, write)]
+    fn remove_last_holder() -> Identity;
+}
+
+storage {
+    owner: Identity = Identity::Address(Address::zero()),
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    holders: StorageVec<Identity> = StorageVec {},
+    paused: bool = false,
+}
+
+#[storage(read)]
+fn only_owner() {
+    require(msg_sender().unwrap() == storage.owner.read(), "Not owner");
+}
+
+impl Vault for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        let sender = msg_sender().unwrap();
+        let balance = storage.balances.get(sender).try_read().unwrap_or(0);
+
+        if balance == 0 {
+            storage.holders.push(sender);
+        }
+
+        storage.balances.insert(sender, balance + msg_amount());
+    }
+
+    #[storage(read, write)]
+    fn withdraw(amount: u64) {
+        require(!storage.paused.read(), "Paused");
+
+        let sender = msg_sender().unwrap();
+        let balance = storage.balances.get(sender).try_read().unwrap_or(0);
+        require(balance >= amount, "Insufficient balance");
+
+        storage.balances.insert(sender, balance - amount);
+        transfer(sender, AssetId::base(), amount);
+    }
+
+    #[storage(read, write)]
+    fn pause() {
+        only_owner();
+        storage.paused.write(true);
+    }
+
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64 {
+        storage.balances.get(owner).try_read().unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn holder_count() -> u64 {
+        storage.holders.len()
+    }
+
+    #[storage(read, write)]
+    fn remove_last_holder() -> Identity {
+        storage.holders.pop().unwrap()
+    }
+}
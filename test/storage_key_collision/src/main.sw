@@ -0,0 +1,46 @@
+contract;
+
+const BALANCE_KEY: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+
+#[namespace(collision)]
+storage {
+    // Report entry should not be created
+    balance in 0x0000000000000000000000000000000000000000000000000000000000000001: u64 = 0,
+
+    // Report entry should be created:
+    // L12: The `shadow_balance` storage field has the same explicit storage key as the `balance` storage field. Consider using a unique storage key.
+    shadow_balance in 0x0000000000000000000000000000000000000000000000000000000000000001: u64 = 0,
+
+    // Report entry should be created:
+    // L16: The `cached_balance` storage field has the same explicit storage key as the `balance` storage field. Consider using a unique storage key.
+    cached_balance in BALANCE_KEY: u64 = 0,
+
+    // Report entry should not be created
+    total: u64 = 0,
+
+    // Report entry should be created:
+    // L23: The `total_copy` storage field has an explicit storage key equal to the default storage key of the `total` storage field. Consider using a unique storage key.
+    total_copy in 0x7890be9695c8c741d6d7e802c1dd166cface181a5b4ad09e7852004e9869a494: u64 = 0,
+
+    // Report entry should be created:
+    // L27: The `admin` storage field has an explicit storage key which overlaps the SRC-14 proxy owner slot. Consider using a storage key which does not collide with the proxy's storage.
+    admin in 0xbb79927b15d9259ea316f2ecb2297d6cc8851888a98278c0a2e03e1a091ea754: Identity = Identity::Address(Address::zero()),
+
+    // Report entry should not be created
+    legacy_total in 0x55e395a1bb8e9d365467de2593b3b29eaf12b66f09f541722fcc61f25a591ae0: u64 = 0,
+
+    // Report entry should not be created
+    supply in 0x0000000000000000000000000000000000000000000000000000000000000002: u64 = 0,
+}
+
+abi TestStorageKeyCollision {
+    #[storage(read)]
+    fn test_storage_key_collision() -> u64;
+}
+
+impl TestStorageKeyCollision for Contract {
+    #[storage(read)]
+    fn test_storage_key_collision() -> u64 {
+        storage.balance.read() + storage.total.read() + storage.supply.read()
+    }
+}
@@ -0,0 +1,59 @@
+contract;
+
+use std::block::{height, timestamp};
+
+const DEADLINE: u64 = 1735689600;
+
+abi TestTimestampUnitConfusion {
+    fn test_timestamp_unit_confusion_1() -> bool;
+    fn test_timestamp_unit_confusion_2() -> bool;
+    fn test_timestamp_unit_confusion_3() -> bool;
+    fn test_timestamp_unit_confusion_4() -> u64;
+    fn test_timestamp_unit_confusion_5() -> bool;
+    fn test_timestamp_unit_confusion_6(start: u64) -> bool;
+}
+
+impl TestTimestampUnitConfusion for Contract {
+    fn test_timestamp_unit_confusion_1() -> bool {
+        // Report entry should be created:
+        // L20: The `Contract::test_timestamp_unit_confusion_1` function compares a TAI64 timestamp against `1700000000`, which looks like a Unix timestamp: `timestamp() > 1700000000`. Consider converting between TAI64 and Unix timestamps.
+        timestamp() > 1700000000
+    }
+
+    fn test_timestamp_unit_confusion_2() -> bool {
+        let now = std::block::timestamp();
+
+        // Report entry should be created:
+        // L28: The `Contract::test_timestamp_unit_confusion_2` function compares a TAI64 timestamp against `DEADLINE`, which looks like a Unix timestamp: `DEADLINE <= now`. Consider converting between TAI64 and Unix timestamps.
+        DEADLINE <= now
+    }
+
+    fn test_timestamp_unit_confusion_3() -> bool {
+        let expiry = timestamp() + 3600;
+
+        // Report entry should be created:
+        // L36: The `Contract::test_timestamp_unit_confusion_3` function compares a TAI64 timestamp against `1700000000`, which looks like a Unix timestamp: `expiry < 1700000000`. Consider converting between TAI64 and Unix timestamps.
+        expiry < 1700000000
+    }
+
+    fn test_timestamp_unit_confusion_4() -> u64 {
+        let start_height = height();
+
+        // Report entry should be created:
+        // L44: The `Contract::test_timestamp_unit_confusion_4` function mixes a TAI64 timestamp with a block height: `timestamp() - start_height`. Consider using consistent units of time.
+        timestamp() - start_height
+    }
+
+    fn test_timestamp_unit_confusion_5() -> bool {
+        // Report entry should be created:
+        // L50: The `Contract::test_timestamp_unit_confusion_5` function mixes a TAI64 timestamp with a block height: `height() + 100 < timestamp()`. Consider using consistent units of time.
+        height() + 100 < timestamp()
+    }
+
+    fn test_timestamp_unit_confusion_6(start: u64) -> bool {
+        let elapsed = timestamp() - start;
+
+        // Report entry should not be created
+        elapsed > 3600 && timestamp() > start
+    }
+}
@@ -0,0 +1,84 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+storage {
+    owner: Identity = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000001)),
+    entries: StorageVec<u64> = StorageVec {},
+    admins: StorageVec<Identity> = StorageVec {},
+}
+
+abi TestUnboundedStorageIteration {
+    #[storage(read, write)]
+    fn add_entry(value: u64);
+    #[storage(read, write)]
+    fn add_admin(admin: Identity);
+    #[storage(read)]
+    fn test_unbounded_storage_iteration_1() -> u64;
+    #[storage(read)]
+    fn test_unbounded_storage_iteration_2() -> u64;
+    #[storage(read)]
+    fn test_unbounded_storage_iteration_3() -> u64;
+}
+
+impl TestUnboundedStorageIteration for Contract {
+    #[storage(read, write)]
+    fn add_entry(value: u64) {
+        storage.entries.push(value);
+    }
+
+    #[storage(read, write)]
+    fn add_admin(admin: Identity) {
+        require(msg_sender().unwrap() == storage.owner.read(), "not owner");
+        storage.admins.push(admin);
+    }
+
+    #[storage(read)]
+    fn test_unbounded_storage_iteration_1() -> u64 {
+        let mut i = 0;
+        let mut total = 0;
+
+        // Report entry should be created:
+        // L43: The `Contract::test_unbounded_storage_iteration_1` function contains a loop bounded by the length of `storage.entries`, which can be grown without access restriction by the `Contract::add_entry` function. Consider limiting the number of iterations.
+        while i < storage.entries.len() {
+            total += storage.entries.get(i).unwrap().read();
+            i += 1;
+        }
+
+        total
+    }
+
+    #[storage(read)]
+    fn test_unbounded_storage_iteration_2() -> u64 {
+        let mut i = 0;
+        let mut total = 0;
+        let len = storage.entries.len();
+
+        // Report entry should be created:
+        // L59: The `Contract::test_unbounded_storage_iteration_2` function contains a loop bounded by the length of `storage.entries`, which can be grown without access restriction by the `Contract::add_entry` function. Consider limiting the number of iterations.
+        while i < len {
+            total += storage.entries.get(i).unwrap().read();
+            i += 1;
+        }
+
+        total
+    }
+
+    #[storage(read)]
+    fn test_unbounded_storage_iteration_3() -> u64 {
+        let mut i = 0;
+
+        // Report entry should not be created
+        while i < storage.admins.len() {
+            i += 1;
+        }
+
+        i
+    }
+}
+
+// Growth from functions which are not publicly accessible should not be considered
+#[storage(read, write)]
+fn grow_admins(admin: Identity) {
+    storage.admins.push(admin);
+}
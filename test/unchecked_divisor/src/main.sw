@@ -0,0 +1,82 @@
+contract;
+
+use std::context::msg_amount;
+
+storage {
+    divisor: u64 = 0,
+}
+
+abi TestUncheckedDivisor {
+    fn test_unchecked_divisor_1(a: u64, b: u64) -> u64;
+    fn test_unchecked_divisor_2(a: u64, b: u64) -> u64;
+    #[payable]
+    fn test_unchecked_divisor_3() -> u64;
+    #[storage(read)]
+    fn test_unchecked_divisor_4(a: u64) -> u64;
+    fn test_unchecked_divisor_5(a: u64, b: u64) -> u64;
+    fn test_unchecked_divisor_6(a: u64, b: u64) -> u64;
+    fn test_unchecked_divisor_7(a: u64) -> u64;
+    fn test_unchecked_divisor_8(a: u64, b: u64) -> u64;
+}
+
+impl TestUncheckedDivisor for Contract {
+    fn test_unchecked_divisor_1(a: u64, b: u64) -> u64 {
+        // Report entry should be created:
+        // L26: The `Contract::test_unchecked_divisor_1` function contains a division by a function parameter which is not checked for a zero value: `a / b`. Consider requiring `b` to be non-zero.
+        a / b
+    }
+
+    fn test_unchecked_divisor_2(a: u64, b: u64) -> u64 {
+        require(b != 0, "b is zero");
+
+        // Report entry should not be created
+        a / b
+    }
+
+    #[payable]
+    fn test_unchecked_divisor_3() -> u64 {
+        let amount = msg_amount();
+
+        // Report entry should be created:
+        // L42: The `Contract::test_unchecked_divisor_3` function contains a division by `msg_amount()` which is not checked for a zero value: `100 / amount`. Consider requiring `amount` to be non-zero.
+        100 / amount
+    }
+
+    #[storage(read)]
+    fn test_unchecked_divisor_4(a: u64) -> u64 {
+        // Report entry should be created:
+        // L49: The `Contract::test_unchecked_divisor_4` function contains a division by a storage read which is not checked for a zero value: `a % storage.divisor.read()`. Consider requiring `storage.divisor.read()` to be non-zero.
+        a % storage.divisor.read()
+    }
+
+    fn test_unchecked_divisor_5(a: u64, b: u64) -> u64 {
+        if b > 0 {
+            // Report entry should not be created
+            a / b
+        } else {
+            0
+        }
+    }
+
+    fn test_unchecked_divisor_6(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            revert(0);
+        }
+
+        // Report entry should not be created
+        a / b
+    }
+
+    fn test_unchecked_divisor_7(a: u64) -> u64 {
+        // Report entry should not be created
+        a / 10
+    }
+
+    fn test_unchecked_divisor_8(a: u64, b: u64) -> u64 {
+        require(a != 0, "a is zero");
+
+        // Report entry should be created:
+        // L80: The `Contract::test_unchecked_divisor_8` function contains a division by a function parameter which is not checked for a zero value: `a / b`. Consider requiring `b` to be non-zero.
+        a / b
+    }
+}
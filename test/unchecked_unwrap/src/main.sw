@@ -0,0 +1,71 @@
+contract;
+
+abi Oracle {
+    fn price() -> Option<u64>;
+}
+
+const ORACLE_ID = 0x79fa8779bed2f36c3581d01c79df8da45eee09fac1fd76a5a656e16326317ef0;
+
+storage {
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    owner: Identity = Identity::Address(Address::from(ORACLE_ID)),
+}
+
+abi TestUncheckedUnwrap {
+    #[storage(read)]
+    fn test_unchecked_unwrap_1(account: Identity) -> u64;
+    fn test_unchecked_unwrap_2() -> Identity;
+    fn test_unchecked_unwrap_3() -> u64;
+    fn test_unchecked_unwrap_4(value: Option<u64>) -> u64;
+    #[storage(read)]
+    fn test_unchecked_unwrap_5() -> Identity;
+    fn test_unchecked_unwrap_6(value: Option<u64>) -> u64;
+}
+
+fn helper(value: Option<u64>) -> u64 {
+    // Report entry should not be created
+    value.unwrap()
+}
+
+impl TestUncheckedUnwrap for Contract {
+    #[storage(read)]
+    fn test_unchecked_unwrap_1(account: Identity) -> u64 {
+        // Report entry should be created:
+        // L35: The `Contract::test_unchecked_unwrap_1` function contains an unchecked `unwrap` on a storage read: `storage.balances.get(account).try_read().unwrap()`. Consider handling the failure case explicitly.
+        storage.balances.get(account).try_read().unwrap()
+    }
+
+    fn test_unchecked_unwrap_2() -> Identity {
+        // Report entry should be created:
+        // L41: The `Contract::test_unchecked_unwrap_2` function contains an unchecked `unwrap` on the result of `msg_sender()`: `msg_sender().unwrap()`. Consider handling the failure case explicitly.
+        msg_sender().unwrap()
+    }
+
+    fn test_unchecked_unwrap_3() -> u64 {
+        let oracle = abi(Oracle, ORACLE_ID);
+
+        // Report entry should be created:
+        // L49: The `Contract::test_unchecked_unwrap_3` function contains an unchecked `unwrap` on the result of an external call: `oracle.price().unwrap()`. Consider handling the failure case explicitly.
+        oracle.price().unwrap()
+    }
+
+    fn test_unchecked_unwrap_4(value: Option<u64>) -> u64 {
+        // Report entry should be created:
+        // L55: The `Contract::test_unchecked_unwrap_4` function contains an unchecked `unwrap` on a function parameter: `value.unwrap()`. Consider handling the failure case explicitly.
+        value.unwrap()
+    }
+
+    #[storage(read)]
+    fn test_unchecked_unwrap_5() -> Identity {
+        let owner = storage.owner.try_read();
+
+        // Report entry should be created:
+        // L64: The `Contract::test_unchecked_unwrap_5` function contains an unchecked `expect` on a storage read: `owner.expect("owner not set")`. Consider handling the failure case explicitly.
+        owner.expect("owner not set")
+    }
+
+    fn test_unchecked_unwrap_6(value: Option<u64>) -> u64 {
+        // Report entry should not be created
+        value.unwrap_or(0)
+    }
+}
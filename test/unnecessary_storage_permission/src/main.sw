@@ -0,0 +1,108 @@
+contract;
+
+storage {
+    counter: u64 = 0,
+    items: StorageVec<u64> = StorageVec {},
+    balances: StorageMap<u64, u64> = StorageMap {},
+}
+
+#[storage(read, write)]
+fn increment() {
+    storage.counter.write(storage.counter.read() + 1);
+}
+
+abi TestUnnecessaryStoragePermission {
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_1() -> u64;
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_2();
+    #[storage(read)]
+    fn test_unnecessary_storage_permission_3();
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_4();
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_5();
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_6();
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_7();
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_8() -> u64;
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_9();
+}
+
+impl TestUnnecessaryStoragePermission for Contract {
+    // Report entry should be created:
+    // L39: The `Contract::test_unnecessary_storage_permission_1` function is declared with the `write` storage permission but never writes to storage. Consider removing the `write` permission.
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_1() -> u64 {
+        storage.counter.read()
+    }
+
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_2() {
+        storage.counter.write(1);
+    }
+
+    // Report entry should be created:
+    // L52: The `Contract::test_unnecessary_storage_permission_3` function is declared with the `read` storage permission but only discards the values it reads from storage. Consider removing the `read` permission.
+    #[storage(read)]
+    fn test_unnecessary_storage_permission_3() {
+        let _ = storage.counter.read();
+    }
+
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_4() {
+        storage.items.push(1);
+    }
+
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_5() {
+        increment();
+    }
+
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_6() {
+        storage.balances.insert(1, 2);
+    }
+
+    // Report entry should not be created
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_7() {
+        reset();
+    }
+
+    // Report entry should be created:
+    // L83: The `Contract::test_unnecessary_storage_permission_8` function is declared with the `write` storage permission but never writes to storage. Consider removing the `write` permission.
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_8() -> u64 {
+        get_counter()
+    }
+
+    // Report entry should be created:
+    // L90: The `Contract::test_unnecessary_storage_permission_9` function is declared with the `read` storage permission but never reads from storage. Consider removing the `read` permission.
+    #[storage(read, write)]
+    fn test_unnecessary_storage_permission_9() {
+        let _ = storage.balances.get(1).clear();
+    }
+}
+
+#[storage(read, write)]
+fn reset() {
+    clear_counter();
+}
+
+#[storage(read, write)]
+fn clear_counter() {
+    storage.counter.write(0);
+}
+
+#[storage(read)]
+fn get_counter() -> u64 {
+    storage.counter.read()
+}
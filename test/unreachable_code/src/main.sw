@@ -0,0 +1,79 @@
+contract;
+
+abi TestUnreachableCode {
+    fn test_unreachable_code_1(value: u64) -> u64;
+    fn test_unreachable_code_2(value: u64) -> u64;
+    fn test_unreachable_code_3() -> u64;
+    fn test_unreachable_code_4() -> u64;
+    fn test_unreachable_code_5(value: u64) -> u64;
+    fn test_unreachable_code_6() -> u64;
+}
+
+impl TestUnreachableCode for Contract {
+    fn test_unreachable_code_1(value: u64) -> u64 {
+        if value == 0 {
+            revert(0);
+
+            // Report entry should be created:
+            // L19: The `Contract::test_unreachable_code_1` function contains unreachable code after `revert(0)`: `let x = 1;`. Consider removing the unreachable code.
+            let x = 1;
+        }
+
+        value
+    }
+
+    fn test_unreachable_code_2(value: u64) -> u64 {
+        return value;
+
+        // Report entry should be created:
+        // L30: The `Contract::test_unreachable_code_2` function contains unreachable code after `return value`: `value + 1`. Consider removing the unreachable code.
+        value + 1
+    }
+
+    fn test_unreachable_code_3() -> u64 {
+        require(false, "disabled");
+
+        // Report entry should be created:
+        // L38: The `Contract::test_unreachable_code_3` function contains unreachable code after `require(false, "disabled")`: `0`. Consider removing the unreachable code.
+        0
+    }
+
+    fn test_unreachable_code_4() -> u64 {
+        let mut i = 0;
+
+        while true {
+            i += 1;
+        }
+
+        // Report entry should be created:
+        // L50: The `Contract::test_unreachable_code_4` function contains unreachable code after `while true { ... }`: `i`. Consider removing the unreachable code.
+        i
+    }
+
+    fn test_unreachable_code_5(value: u64) -> u64 {
+        if value == 0 {
+            revert(0);
+        } else {
+            revert(1);
+        }
+
+        // Report entry should be created:
+        // L62: The `Contract::test_unreachable_code_5` function contains unreachable code after `if value == 0 { ... } else { ... }`: `value`. Consider removing the unreachable code.
+        value
+    }
+
+    fn test_unreachable_code_6() -> u64 {
+        let mut i = 0;
+
+        while true {
+            i += 1;
+
+            if i == 10 {
+                break;
+            }
+        }
+
+        // Report entry should not be created
+        i
+    }
+}
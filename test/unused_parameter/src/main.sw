@@ -0,0 +1,37 @@
+contract;
+
+struct Deposit {
+    sender: Identity,
+    amount: u64,
+}
+
+// Report entry should be created:
+// L10: The `scale` function does not use its `factor: u64` parameter. Consider validating the parameter, removing it or prefixing its name with an underscore.
+fn scale(value: u64, factor: u64) -> u64 {
+    value * 2
+}
+
+abi TestUnusedParameter {
+    fn test_unused_parameter_1(recipient: Identity, amount: u64);
+    fn test_unused_parameter_2(note: b256, _unused: u64);
+    fn test_unused_parameter_3(sender: Identity, amount: u64) -> Deposit;
+}
+
+impl TestUnusedParameter for Contract {
+    // Report entry should be created:
+    // L23: The `Contract::test_unused_parameter_1` function does not use its `amount: u64` parameter. Consider validating the parameter, removing it or prefixing its name with an underscore.
+    fn test_unused_parameter_1(recipient: Identity, amount: u64) {
+        log(recipient);
+    }
+
+    // Report entry should be created:
+    // L29: The `Contract::test_unused_parameter_2` function does not use its `note: b256` parameter. Consider validating the parameter, removing it or prefixing its name with an underscore.
+    fn test_unused_parameter_2(note: b256, _unused: u64) {
+        log(scale(1, 2));
+    }
+
+    // Report entry should not be created
+    fn test_unused_parameter_3(sender: Identity, amount: u64) -> Deposit {
+        Deposit { sender, amount }
+    }
+}
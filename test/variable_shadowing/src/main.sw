@@ -0,0 +1,54 @@
+contract;
+
+configurable {
+    fee: u64 = 100,
+}
+
+storage {
+    owner: Identity = Identity::Address(Address::zero()),
+    total: u64 = 0,
+}
+
+abi TestVariableShadowing {
+    fn test_variable_shadowing_1(amount: u64) -> u64;
+    #[storage(read)]
+    fn test_variable_shadowing_2() -> bool;
+    fn test_variable_shadowing_3() -> u64;
+    #[storage(read)]
+    fn test_variable_shadowing_4() -> u64;
+}
+
+impl TestVariableShadowing for Contract {
+    fn test_variable_shadowing_1(amount: u64) -> u64 {
+        // Report entry should be created:
+        // L25: The `Contract::test_variable_shadowing_1` function declares the `amount` variable which shadows the `amount` parameter declared on L22. Consider renaming the variable.
+        let amount = amount * 2;
+
+        // Report entry should not be created
+        let amount = amount + 1;
+
+        amount
+    }
+
+    #[storage(read)]
+    fn test_variable_shadowing_2() -> bool {
+        // Report entry should be created:
+        // L37: The `Contract::test_variable_shadowing_2` function declares the `owner` variable which shadows the `owner` storage field declared on L8. Consider renaming the variable.
+        let owner = msg_sender().unwrap();
+        owner == storage.owner.read()
+    }
+
+    fn test_variable_shadowing_3() -> u64 {
+        // Report entry should be created:
+        // L44: The `Contract::test_variable_shadowing_3` function declares the `fee` variable which shadows the `fee` configurable declared on L4. Consider renaming the variable.
+        let fee = 5;
+        fee
+    }
+
+    #[storage(read)]
+    fn test_variable_shadowing_4() -> u64 {
+        // Report entry should not be created
+        let owner = 1;
+        owner + storage.total.read()
+    }
+}